@@ -115,7 +115,7 @@ macro_rules! impl_deref_mut {
         impl<$($generics),* : ?Sized $(+ $traits)?> DerefMut for $name<$($generics),*> {
             #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
-                #[cfg(featgure = "change_detection")]
+                #[cfg(feature = "change_detection")]
                 self.set_changed();
                 self.value
             }
@@ -357,6 +357,37 @@ impl<'w, T: ?Sized> Ref<'w, T> {
         self.value
     }
 
+    /// Returns a `Ref<>` with a smaller lifetime, pointing to the same value and ticks.
+    ///
+    /// This is useful to pass a `Ref<T>` to a function taking it by value without giving it up.
+    ///
+    /// ```
+    /// # use ens::prelude::*;
+    /// # #[derive(Component)] pub struct Health(u32);
+    /// fn describe(health: Ref<Health>) -> String {
+    ///     let changed = if health.is_changed() { " (changed)" } else { "" };
+    ///     format!("{}{changed}", health.into_inner().0)
+    /// }
+    ///
+    /// fn log_health(query: Query<Ref<Health>>) {
+    ///     for health in &query {
+    ///         let description = describe(health.reborrow());
+    ///         if health.is_added() {
+    ///             println!("spawned with {description} health");
+    ///         }
+    ///     }
+    /// }
+    /// # ens::system::assert_is_system(log_health);
+    /// ```
+    #[inline]
+    pub fn reborrow(&self) -> Ref<'_, T> {
+        Ref {
+            value: self.value,
+            #[cfg(feature = "change_detection")]
+            ticks: self.ticks.clone(),
+        }
+    }
+
     /// Map `Ref` to a different type using `f`.
     ///
     /// This doesn't do anything else than call `f` on the wrapped value.
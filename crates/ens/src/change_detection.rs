@@ -12,6 +12,8 @@ use ens_ptr::{Ptr, UnsafeCellDeref};
 use std::mem;
 use std::ops::{Deref, DerefMut};
 
+pub use crate::CHANGE_DETECTION_ENABLED;
+
 /// The (arbitrarily chosen) minimum number of world tick increments between `check_tick` scans.
 ///
 /// Change ticks can only be scanned when systems aren't running. Thus, if the threshold is `N`,
@@ -30,6 +32,9 @@ pub const MAX_CHANGE_AGE: u32 = u32::MAX - (2 * CHECK_TICK_THRESHOLD - 1);
 /// Types that can read change detection information.
 /// This change detection is controlled by [`DetectChangesMut`] types such as [`ResMut`].
 ///
+/// This trait only exists when the `change_detection` feature is enabled, so a build without it
+/// fails to compile instead of reporting a made-up value. See [`CHANGE_DETECTION_ENABLED`].
+///
 /// ## Example
 /// Using types that implement [`DetectChanges`], such as [`Res`], provide
 /// a way to query if a value has been mutated in another system.
@@ -56,6 +61,10 @@ pub trait DetectChanges {
     ///
     /// To check if the value was mutably dereferenced only,
     /// use `this.is_changed() && !this.is_added()`.
+    ///
+    /// In debug builds, flagging a value as changed through [`DetectChangesMut`] panics if this
+    /// would still return `false` afterwards, which happens when the ticks the value was fetched
+    /// with don't advance between runs.
     fn is_changed(&self) -> bool;
 
     /// Returns the change tick recording the time this data was most recently changed.
@@ -66,6 +75,12 @@ pub trait DetectChanges {
     /// [`SystemChangeTick`](crate::system::SystemChangeTick)
     /// [`SystemParam`](crate::system::SystemParam).
     fn last_changed(&self) -> Tick;
+
+    /// Returns the change tick recording the time this data was added.
+    ///
+    /// Unlike [`last_changed`](DetectChanges::last_changed), this is not updated by later
+    /// mutations, so the two are equal until the value is first changed after being added.
+    fn added(&self) -> Tick;
 }
 
 /// Types that implement reliable change detection.
@@ -128,6 +143,17 @@ pub trait DetectChangesMut: DetectChanges {
     /// you are trying to synchronize representations using change detection and need to avoid infinite recursion.
     fn bypass_change_detection(&mut self) -> &mut Self::Inner;
 
+    /// Runs `f` on the underlying value without updating the change tick, no matter how `f`
+    /// accesses it.
+    ///
+    /// This is a scoped form of [`bypass_change_detection`](DetectChangesMut::bypass_change_detection),
+    /// convenient for bulk updates, such as syncing from an authoritative copy, that must not be
+    /// seen as changes. The same warnings apply.
+    #[inline]
+    fn with_bypassed(&mut self, f: impl FnOnce(&mut Self::Inner)) {
+        f(self.bypass_change_detection());
+    }
+
     /// Overwrites this smart pointer with the given value, if and only if `*self != value`.
     /// Returns `true` if the value was overwritten, and returns `false` if it was not.
     ///
@@ -167,9 +193,41 @@ pub trait DetectChangesMut: DetectChanges {
     fn set_if_neq(&mut self, value: Self::Inner) -> bool
     where
         Self::Inner: Sized + PartialEq,
+    {
+        self.set_if_neq_by(value, PartialEq::eq)
+    }
+
+    /// Overwrites this smart pointer with the given value, if and only if `eq(&*self, &value)`
+    /// returns `false`. Returns `true` if the value was overwritten, and returns `false` if it
+    /// was not.
+    ///
+    /// This works like [`set_if_neq`](DetectChangesMut::set_if_neq), but with a custom equality,
+    /// e.g. to ignore small differences between floats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ens::prelude::*;
+    /// #[derive(Resource)]
+    /// pub struct Volume(f32);
+    ///
+    /// fn set_volume(mut volume: ResMut<Volume>) {
+    ///     // Only flag the volume as changed if the difference is audible.
+    ///     volume.set_if_neq_by(Volume(0.5), |old, new| (old.0 - new.0).abs() < 0.01);
+    /// }
+    /// # ens::system::assert_is_system(set_volume);
+    /// ```
+    #[inline]
+    fn set_if_neq_by(
+        &mut self,
+        value: Self::Inner,
+        eq: impl FnOnce(&Self::Inner, &Self::Inner) -> bool,
+    ) -> bool
+    where
+        Self::Inner: Sized,
     {
         let old = self.bypass_change_detection();
-        if *old != value {
+        if !eq(old, &value) {
             *old = value;
             self.set_changed();
             true
@@ -250,6 +308,77 @@ pub trait DetectChangesMut: DetectChanges {
     }
 }
 
+/// An extension trait for iterators, such as [`Query::iter`](crate::system::Query::iter), that
+/// keeps only the changed items and pairs them with the tick they last changed on.
+///
+/// ```
+/// use ens::prelude::*;
+/// use ens::change_detection::IterChangedExt;
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// fn send_updates(query: Query<(Entity, Ref<Position>)>) {
+///     let mut updates: Vec<_> = query
+///         .iter()
+///         .changed_with_ticks(|(_, position)| position)
+///         .collect();
+///     // Oldest changes first
+///     updates.sort_by_key(|(_, tick)| tick.get());
+/// }
+/// # ens::system::assert_is_system(send_updates);
+/// ```
+pub trait IterChangedExt: Iterator + Sized {
+    /// Yields `(item, tick)` for every item whose [`DetectChanges`] value returned by `f` is
+    /// [changed](DetectChanges::is_changed), where `tick` is its
+    /// [`last_changed`](DetectChanges::last_changed) tick. Unchanged items are skipped.
+    fn changed_with_ticks<C, F>(self, f: F) -> ChangedWithTicks<Self, F>
+    where
+        C: DetectChanges + ?Sized,
+        F: FnMut(&Self::Item) -> &C;
+}
+
+impl<I: Iterator> IterChangedExt for I {
+    fn changed_with_ticks<C, F>(self, f: F) -> ChangedWithTicks<Self, F>
+    where
+        C: DetectChanges + ?Sized,
+        F: FnMut(&Self::Item) -> &C,
+    {
+        ChangedWithTicks { iter: self, f }
+    }
+}
+
+/// An iterator over changed items and their change ticks, see
+/// [`IterChangedExt::changed_with_ticks`].
+pub struct ChangedWithTicks<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, C, F> Iterator for ChangedWithTicks<I, F>
+where
+    I: Iterator,
+    C: DetectChanges + ?Sized,
+    F: FnMut(&I::Item) -> &C,
+{
+    type Item = (I::Item, Tick);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            let value = (self.f)(&item);
+            if value.is_changed() {
+                let tick = value.last_changed();
+                return Some((item, tick));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
 macro_rules! change_detection_impl {
     ($name:ident < $( $generics:tt ),+ >, $target:ty, $($traits:ident)?) => {
         impl<$($generics),* : ?Sized $(+ $traits)?> DetectChanges for $name<$($generics),*> {
@@ -271,6 +400,11 @@ macro_rules! change_detection_impl {
             fn last_changed(&self) -> Tick {
                 *self.ticks.changed
             }
+
+            #[inline]
+            fn added(&self) -> Tick {
+                *self.ticks.added
+            }
         }
     }
 }
@@ -285,6 +419,7 @@ macro_rules! change_detection_mut_impl {
             #[inline]
             fn set_changed(&mut self) {
                 *self.ticks.changed = self.ticks.this_run;
+                $crate::change_detection::debug_assert_changed(self.ticks.last_run, self.ticks.this_run);
             }
 
             #[inline]
@@ -302,6 +437,19 @@ macro_rules! change_detection_mut_impl {
 
 pub(crate) use change_detection_mut_impl;
 
+/// Catches a value being flagged as changed while `is_changed` keeps returning `false`, which
+/// happens when `last_run` and `this_run` are the same tick.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn debug_assert_changed(last_run: Tick, this_run: Tick) {
+    debug_assert!(
+        last_run != this_run,
+        "a value was mutated at tick {this_run:?}, the same tick its reader last ran at, so the \
+        change will not be detected; make sure the change tick advances between runs, e.g. by \
+        calling `World::clear_trackers`"
+    );
+}
+
 #[derive(Clone)]
 pub(crate) struct Ticks<'w> {
     pub(crate) added: &'w Tick,
@@ -391,6 +539,11 @@ impl<'w> DetectChanges for MutUntyped<'w> {
     fn last_changed(&self) -> Tick {
         *self.ticks.changed
     }
+
+    #[inline(always)]
+    fn added(&self) -> Tick {
+        *self.ticks.added
+    }
 }
 
 #[cfg(feature = "change_detection")]
@@ -400,6 +553,7 @@ impl<'w> DetectChangesMut for MutUntyped<'w> {
     #[inline(always)]
     fn set_changed(&mut self) {
         *self.ticks.changed = self.ticks.this_run;
+        debug_assert_changed(self.ticks.last_run, self.ticks.this_run);
     }
 
     #[inline(always)]
@@ -424,11 +578,12 @@ mod tests {
         access::{Mut, NonSendMut, Ref, ResMut},
         change_detection::{TicksMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE},
         component::{Component, ComponentTicks, Tick},
+        entity::Entity,
         system::{IntoSystem, Query, System},
         world::World,
     };
 
-    use super::{DetectChanges, DetectChangesMut, MutUntyped};
+    use super::{DetectChanges, DetectChangesMut, IterChangedExt, MutUntyped};
 
     #[derive(Component, PartialEq)]
     struct C;
@@ -487,6 +642,40 @@ mod tests {
         assert!(!change_expired_system.run((), &mut world));
     }
 
+    #[test]
+    fn changed_with_ticks_yields_change_ticks() {
+        #[derive(Component)]
+        struct Value(u32);
+
+        fn changed(query: Query<(Entity, Ref<Value>)>) -> Vec<(Entity, Tick)> {
+            query
+                .iter()
+                .changed_with_ticks(|(_, value)| value)
+                .map(|((entity, _), tick)| (entity, tick))
+                .collect()
+        }
+
+        let mut world = World::new();
+        let a = world.spawn(Value(0)).id();
+        let b = world.spawn(Value(0)).id();
+        world.spawn(Value(0));
+
+        let mut system = IntoSystem::into_system(changed);
+        system.initialize(&mut world);
+        assert_eq!(system.run((), &mut world).len(), 3);
+
+        world.increment_change_tick();
+        world.get_mut::<Value>(a).unwrap().0 = 1;
+        world.increment_change_tick();
+        world.get_mut::<Value>(b).unwrap().0 = 2;
+
+        let changes = system.run((), &mut world);
+        assert_eq!(changes.len(), 2);
+        let tick_of = |entity| changes.iter().find(|(e, _)| *e == entity).unwrap().1;
+        assert_ne!(tick_of(a), tick_of(b));
+        assert!(tick_of(b).is_newer_than(tick_of(a), world.change_tick()));
+    }
+
     #[test]
     fn change_tick_wraparound() {
         let mut world = World::new();
@@ -504,6 +693,41 @@ mod tests {
         assert!(query.single(&world).is_changed());
     }
 
+    #[test]
+    fn strict_change_detection() {
+        let mut world = World::new();
+        world.set_strict_change_detection(true);
+        world.spawn(C);
+        world.insert_resource(R);
+        world.clear_trackers();
+
+        // a bunch of stuff happens, then the ticks are scanned as a schedule would
+        *world.change_tick.get_mut() += MAX_CHANGE_AGE + CHECK_TICK_THRESHOLD;
+        world.check_change_ticks();
+        let check_tick = world.read_change_tick();
+        world.clear_trackers();
+
+        assert!(world.is_strict_change_detection());
+        assert_eq!(world.last_check_tick, check_tick);
+
+        // the scan clamped the resource ticks, so the invariants held
+        let ticks = world.get_resource_change_ticks::<R>().unwrap();
+        assert_eq!(check_tick.relative_to(ticks.added).get(), MAX_CHANGE_AGE);
+        assert_eq!(check_tick.relative_to(ticks.changed).get(), MAX_CHANGE_AGE);
+    }
+
+    #[test]
+    #[should_panic(expected = "ticks between `World::check_change_ticks` scans")]
+    fn strict_change_detection_missed_scan() {
+        let mut world = World::new();
+        world.set_strict_change_detection(true);
+        world.spawn(C);
+
+        // a bunch of stuff happens without the ticks being scanned
+        *world.change_tick.get_mut() += MAX_CHANGE_AGE + CHECK_TICK_THRESHOLD;
+        world.clear_trackers();
+    }
+
     #[test]
     fn change_tick_scan() {
         let mut world = World::new();
@@ -533,4 +757,143 @@ mod tests {
             assert_eq!(ticks_since_change, MAX_CHANGE_AGE);
         }
     }
+
+    #[test]
+    fn mutation_is_detected() {
+        assert!(crate::CHANGE_DETECTION_ENABLED);
+
+        let mut world = World::new();
+        world.insert_resource(R2(0));
+        world.clear_trackers();
+
+        world.resource_mut::<R2>().0 = 1;
+        assert!(world.is_resource_changed::<R2>());
+    }
+
+    #[test]
+    fn set_if_neq_by_epsilon() {
+        #[derive(Resource)]
+        struct Float(f32);
+
+        fn within_epsilon(a: &Float, b: &Float) -> bool {
+            (a.0 - b.0).abs() < 0.1
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Float(1.0));
+        world.clear_trackers();
+
+        let mut float = world.resource_mut::<Float>();
+        assert!(!float.set_if_neq_by(Float(1.05), within_epsilon));
+        assert_eq!(float.0, 1.0);
+        assert!(!world.is_resource_changed::<Float>());
+
+        let mut float = world.resource_mut::<Float>();
+        assert!(float.set_if_neq_by(Float(1.5), within_epsilon));
+        assert_eq!(float.0, 1.5);
+        assert!(world.is_resource_changed::<Float>());
+    }
+
+    #[test]
+    fn added_tick_is_kept_on_change() {
+        fn ticks(query: Query<Ref<C>>) -> (Tick, Tick) {
+            let value = query.single();
+            (value.added(), value.last_changed())
+        }
+
+        let mut world = World::new();
+        world.spawn(C);
+        let spawned = world.change_tick();
+        let mut system = IntoSystem::into_system(ticks);
+        system.initialize(&mut world);
+        assert_eq!(system.run((), &mut world), (spawned, spawned));
+
+        world.clear_trackers();
+        let mut value = world.query::<&mut C>().single_mut(&mut world);
+        value.set_changed();
+        assert_eq!(value.added(), spawned);
+        let changed = value.last_changed();
+        assert!(changed.is_newer_than(spawned, world.change_tick()));
+        assert_eq!(system.run((), &mut world), (spawned, changed));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn undetectable_mutation_panics() {
+        let mut value = 0;
+        let mut added = Tick::new(1);
+        let mut changed = Tick::new(1);
+        let tick = Tick::new(2);
+        let mut value = Mut::new(&mut value, &mut added, &mut changed, tick, tick);
+        *value = 1;
+    }
+
+    #[test]
+    fn with_bypassed_does_not_flag_change() {
+        let mut world = World::new();
+        world.insert_resource(R2(0));
+        world.clear_trackers();
+
+        world.resource_mut::<R2>().with_bypassed(|r2| {
+            r2.0 = 1;
+            **r2 += 1;
+        });
+
+        assert_eq!(world.resource::<R2>().0, 2);
+        assert!(!world.is_resource_changed::<R2>());
+    }
+
+    #[test]
+    fn static_components_skip_change_tick_scan() {
+        let mut world = World::new();
+        world.set_strict_change_detection(true);
+        world.mark_static::<C>();
+        assert!(world.is_static::<C>());
+
+        world.spawn(C);
+        *world.change_tick.get_mut() += MAX_CHANGE_AGE + CHECK_TICK_THRESHOLD;
+        let change_tick = world.change_tick();
+
+        // static components are neither clamped nor checked by strict mode
+        world.check_change_ticks();
+        world.clear_trackers();
+        let mut query = world.query::<Ref<C>>();
+        let tracker = query.single(&world);
+        assert!(change_tick.relative_to(*tracker.ticks.changed).get() > MAX_CHANGE_AGE);
+
+        // unmarking clamps the ticks right away
+        world.unmark_static::<C>();
+        assert!(!world.is_static::<C>());
+        let change_tick = world.change_tick();
+        let tracker = query.single(&world);
+        assert_eq!(
+            change_tick.relative_to(*tracker.ticks.changed).get(),
+            MAX_CHANGE_AGE
+        );
+    }
+
+    #[test]
+    fn static_components_do_not_report_changes() {
+        let mut world = World::new();
+        world.mark_static::<C>();
+        let entity = world.spawn(C).id();
+
+        let mut added = world.query_filtered::<(), crate::query::Added<C>>();
+        let mut changed = world.query_filtered::<(), crate::query::Changed<C>>();
+        let mut tracker = world.query::<Ref<C>>();
+        assert_eq!(added.iter(&world).count(), 0);
+        assert_eq!(changed.iter(&world).count(), 0);
+        let value = tracker.single(&world);
+        assert!(!value.is_added());
+        assert!(!value.is_changed());
+
+        world.get_mut::<C>(entity).unwrap().set_changed();
+        assert_eq!(changed.iter(&world).count(), 0);
+
+        // mutations made while static are reported once unmarked
+        world.unmark_static::<C>();
+        assert_eq!(changed.iter(&world).count(), 1);
+        assert!(tracker.single(&world).is_changed());
+    }
 }
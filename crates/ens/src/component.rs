@@ -279,6 +279,16 @@ impl ComponentInfo {
         &self.descriptor.name
     }
 
+    /// Returns the name of the current component if it is borrowed for the whole program, which is
+    /// the case for the type name of every Rust type.
+    #[inline]
+    pub(crate) fn static_name(&self) -> Option<&'static str> {
+        match self.descriptor.name {
+            Cow::Borrowed(name) => Some(name),
+            Cow::Owned(_) => None,
+        }
+    }
+
     /// Returns the [`TypeId`] of the underlying component type.
     /// Returns `None` if the component does not correspond to a Rust type.
     #[inline]
@@ -776,6 +786,7 @@ impl Components {
 /// A value that tracks when a system ran relative to other systems.
 /// This is used to power change detection.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tick {
     tick: u32,
 }
@@ -875,6 +886,7 @@ impl<'a> TickCells<'a> {
 /// Records when a component or resource was added and when it was last mutably dereferenced (or added).
 #[cfg(feature = "change_detection")]
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComponentTicks {
     pub(crate) added: Tick,
     pub(crate) changed: Tick,
@@ -166,6 +166,13 @@ struct EventInstance<E: Event> {
 /// but can be done by adding your event as a resource instead of using
 /// [`add_event`](https://docs.rs/bevy/*/bevy/app/struct.App.html#method.add_event).
 ///
+/// # Capping
+///
+/// To protect against event floods, [`set_max_per_frame`](Events::set_max_per_frame) limits how
+/// many events are stored between two [`update`](Events::update)s. Events sent past the limit are
+/// dropped and counted in [`dropped_count`](Events::dropped_count). Readers never see them, so
+/// only cap events that are safe to lose.
+///
 /// [Example usage.](https://github.com/bevyengine/bevy/blob/latest/examples/ecs/event.rs)
 /// [Example usage standalone.](https://github.com/bevyengine/bevy/blob/latest/crates/ens/examples/events.rs)
 ///
@@ -177,6 +184,8 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     events_b: EventSequence<E>,
     event_count: usize,
+    max_per_frame: Option<usize>,
+    dropped_count: usize,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -186,6 +195,8 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            max_per_frame: None,
+            dropped_count: 0,
         }
     }
 }
@@ -201,7 +212,25 @@ impl<E: Event> Events<E> {
     /// "Sends" an `event` by writing it to the current event buffer. [`EventReader`]s can then read
     /// the event.
     /// This method returns the [ID](`EventId`) of the sent `event`.
+    ///
+    /// If the [cap](Events::set_max_per_frame) is reached, the event is dropped and the returned
+    /// ID doesn't refer to any event. Use [`try_send`](Events::try_send) to tell whether the
+    /// event was stored.
     pub fn send(&mut self, event: E) -> EventId<E> {
+        self.try_send(event).unwrap_or(EventId {
+            id: usize::MAX,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Like [`send`](Events::send), but returns [`None`] if the event was dropped because the
+    /// [cap](Events::set_max_per_frame) was reached.
+    pub fn try_send(&mut self, event: E) -> Option<EventId<E>> {
+        if self.remaining_capacity() == 0 {
+            self.dropped_count += 1;
+            return None;
+        }
+
         let event_id = EventId {
             id: self.event_count,
             _marker: PhantomData,
@@ -214,7 +243,7 @@ impl<E: Event> Events<E> {
         self.events_b.push(event_instance);
         self.event_count += 1;
 
-        event_id
+        Some(event_id)
     }
 
     /// Sends a list of `events` all at once, which can later be read by [`EventReader`]s.
@@ -241,6 +270,33 @@ impl<E: Event> Events<E> {
         self.send(Default::default())
     }
 
+    /// Limits how many events can be sent between two [`update`](Events::update)s, or removes the
+    /// limit if `max` is [`None`].
+    ///
+    /// Events sent past the limit are dropped and counted in
+    /// [`dropped_count`](Events::dropped_count). This changes the usual guarantee that every sent
+    /// event can be read, so only use it for events that are safe to lose, such as input from
+    /// untrusted clients.
+    pub fn set_max_per_frame(&mut self, max: Option<usize>) {
+        self.max_per_frame = max;
+    }
+
+    /// Returns the limit set with [`set_max_per_frame`](Events::set_max_per_frame).
+    pub fn max_per_frame(&self) -> Option<usize> {
+        self.max_per_frame
+    }
+
+    /// Returns the total number of events dropped because the
+    /// [cap](Events::set_max_per_frame) was reached.
+    pub fn dropped_count(&self) -> usize {
+        self.dropped_count
+    }
+
+    fn remaining_capacity(&self) -> usize {
+        self.max_per_frame
+            .map_or(usize::MAX, |max| max.saturating_sub(self.events_b.len()))
+    }
+
     /// Gets a new [`ManualEventReader`]. This will include all events already in the event buffers.
     pub fn get_reader(&self) -> ManualEventReader<E> {
         ManualEventReader::default()
@@ -363,7 +419,8 @@ impl<E: Event> Extend<E> for Events<E> {
     {
         let old_count = self.event_count;
         let mut event_count = self.event_count;
-        let events = iter.into_iter().map(|event| {
+        let mut iter = iter.into_iter();
+        let events = iter.by_ref().take(self.remaining_capacity()).map(|event| {
             let event_id = EventId {
                 id: event_count,
                 _marker: PhantomData,
@@ -374,6 +431,7 @@ impl<E: Event> Extend<E> for Events<E> {
 
         self.events_b.extend(events);
         self.event_count = event_count;
+        self.dropped_count += iter.count();
     }
 }
 
@@ -535,6 +593,14 @@ impl<'w, E: Event> EventWriter<'w, E> {
         self.events.send(event)
     }
 
+    /// Sends an `event` like [`send`](EventWriter::send), but returns [`None`] if it was dropped
+    /// because the [cap](Events::set_max_per_frame) was reached.
+    ///
+    /// See [`Events`] for details.
+    pub fn try_send(&mut self, event: E) -> Option<EventId<E>> {
+        self.events.try_send(event)
+    }
+
     /// Sends a list of `events` all at once, which can later be read by [`EventReader`]s.
     /// This is more efficient than sending each event individually.
     /// This method returns the [IDs](`EventId`) of the sent `events`.
@@ -1018,6 +1084,28 @@ mod tests {
             .eq([TestEvent { i: 0 }, TestEvent { i: 1 }].iter()));
     }
 
+    #[test]
+    fn test_events_max_per_frame() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        events.set_max_per_frame(Some(2));
+
+        events.send(TestEvent { i: 0 });
+        events.extend((1..4).map(|i| TestEvent { i }));
+        assert_eq!(events.try_send(TestEvent { i: 4 }), None);
+        assert_eq!(events.dropped_count(), 3);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 0 }, TestEvent { i: 1 }]
+        );
+
+        // The cap applies per update
+        events.update();
+        assert!(events.try_send(TestEvent { i: 5 }).is_some());
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 5 }]);
+        assert_eq!(events.dropped_count(), 3);
+    }
+
     #[test]
     fn test_events_empty() {
         let mut events = Events::<TestEvent>::default();
@@ -25,6 +25,16 @@ pub mod event;
 
 pub use ens_ptr as ptr;
 
+/// Whether this build of `ens` tracks changes, i.e. whether the `change_detection` feature is
+/// enabled.
+///
+/// Without the feature, [`change_detection`] and [`Changed`](query::Changed) don't exist and
+/// mutating through [`Mut`](access::Mut) or [`ResMut`](access::ResMut) records nothing. Code that
+/// must compile either way can branch on this constant. It is defined here rather than in
+/// [`change_detection`] so it is available when that module is compiled out, and is re-exported
+/// from there.
+pub const CHANGE_DETECTION_ENABLED: bool = cfg!(feature = "change_detection");
+
 /// Most commonly used re-exported types.
 pub mod prelude {
     #[doc(hidden)]
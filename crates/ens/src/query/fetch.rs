@@ -932,8 +932,13 @@ unsafe impl<'__w, T: Component> WorldQuery for Ref<'__w, T> {
                         .debug_checked_unwrap()
                 }
             }),
+            // Nothing is newer than `this_run`, so static components are never reported
             #[cfg(feature = "change_detection")]
-            last_run,
+            last_run: if world.is_static_component(component_id) {
+                this_run
+            } else {
+                last_run
+            },
             #[cfg(feature = "change_detection")]
             this_run,
         }
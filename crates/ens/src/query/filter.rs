@@ -620,8 +620,13 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
             table_ticks: None,
             sparse_set: (T::STORAGE_TYPE == StorageType::SparseSet)
                 .then(|| world.storages().sparse_sets.get(id).debug_checked_unwrap()),
+            // Nothing is newer than `this_run`, so static components are never reported
             #[cfg(feature = "change_detection")]
-            last_run,
+            last_run: if world.is_static_component(id) {
+                this_run
+            } else {
+                last_run
+            },
             #[cfg(feature = "change_detection")]
             this_run,
         }
@@ -864,7 +869,12 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
             table_ticks: None,
             sparse_set: (T::STORAGE_TYPE == StorageType::SparseSet)
                 .then(|| world.storages().sparse_sets.get(id).debug_checked_unwrap()),
-            last_run,
+            // Nothing is newer than `this_run`, so static components are never reported
+            last_run: if world.is_static_component(id) {
+                this_run
+            } else {
+                last_run
+            },
             this_run,
         }
     }
@@ -202,7 +202,7 @@ pub mod common_conditions {
     };
 
     #[cfg(feature = "change_detection")]
-    use crate::change_detection::DetectChanges;
+    use crate::{change_detection::DetectChanges, query::Changed};
 
     #[cfg(feature = "events")]
     use crate::{
@@ -746,6 +746,52 @@ pub mod common_conditions {
         !query.is_empty()
     }
 
+    /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
+    /// if any entity had a component of the given type added or mutably dereferenced since the
+    /// condition last ran.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ens::prelude::*;
+    /// # #[derive(Resource, Default)]
+    /// # struct Counter(u8);
+    /// # let mut app = Schedule::default();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// app.add_systems(
+    ///     my_system.run_if(any_component_changed::<MyComponent>()),
+    /// );
+    ///
+    /// #[derive(Component)]
+    /// struct MyComponent(u8);
+    ///
+    /// fn my_system(mut counter: ResMut<Counter>) {
+    ///     counter.0 += 1;
+    /// }
+    ///
+    /// // Adding a component counts as a change so `my_system` will run
+    /// let entity = world.spawn(MyComponent(0)).id();
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    ///
+    /// // Nothing changed since the condition last ran so `my_system` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 1);
+    ///
+    /// // `MyComponent` was mutated so `my_system` will run
+    /// world.get_mut::<MyComponent>(entity).unwrap().0 += 1;
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 2);
+    /// ```
+    #[cfg(feature = "change_detection")]
+    pub fn any_component_changed<T: Component>() -> impl FnMut(Query<(), Changed<T>>) -> bool + Clone
+    {
+        // The query's change ticks are relative to the last run of this condition,
+        // so a change is only ever reported once.
+        move |query: Query<(), Changed<T>>| !query.is_empty()
+    }
+
     /// Generates a [`Condition`](super::Condition)-satisfying closure that returns `true`
     /// if there are any entity with a component of the given type removed.
     #[cfg(feature = "events")]
@@ -974,6 +1020,33 @@ mod tests {
         assert_eq!(world.resource::<Counter>().0, 0);
     }
 
+    #[test]
+    fn any_component_changed_fires_once_per_change() {
+        #[derive(Component)]
+        struct Changes(usize);
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(increment_counter.run_if(any_component_changed::<Changes>()));
+
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 0);
+
+        // Adding the component counts as a change
+        let entity = world.spawn(Changes(0)).id();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        world.get_mut::<Changes>(entity).unwrap().0 += 1;
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
+
     #[derive(Resource, PartialEq, Eq, Debug, Default, Hash, Clone)]
     enum TestResource {
         #[default]
@@ -1003,6 +1076,7 @@ mod tests {
                 .distributive_run_if(resource_removed::<TestResource>())
                 .distributive_run_if(on_event::<TestEvent>())
                 .distributive_run_if(any_with_component::<TestComponent>)
+                .distributive_run_if(any_component_changed::<TestComponent>())
                 .distributive_run_if(not(run_once())),
         );
     }
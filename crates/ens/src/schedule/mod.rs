@@ -714,6 +714,32 @@ mod tests {
             let result = schedule.initialize(&mut world);
             assert!(matches!(result, Err(ScheduleBuildError::Ambiguity(_))));
         }
+
+        #[test]
+        fn duplicate_systems() {
+            fn foo() {}
+            fn bar() {}
+
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.add_systems((foo, bar, apply_deferred, apply_deferred));
+            assert!(schedule.initialize(&mut world).is_ok());
+
+            schedule.set_build_settings(ScheduleBuildSettings {
+                duplicate_system_detection: LogLevel::Error,
+                ..Default::default()
+            });
+            assert!(schedule.initialize(&mut world).is_ok());
+
+            schedule.add_systems(foo);
+            let result = schedule.initialize(&mut world);
+            let Err(ScheduleBuildError::DuplicateSystems(message)) = result else {
+                panic!("expected a duplicate system error, got {result:?}");
+            };
+            assert!(message.contains("foo (2 instances)"));
+            assert!(!message.contains("bar"));
+            assert!(!message.contains("apply_deferred"));
+        }
     }
 
     mod system_ambiguity {
@@ -1,4 +1,6 @@
 use std::{
+    any::TypeId,
+    borrow::Cow,
     collections::BTreeSet,
     fmt::{Debug, Write},
 };
@@ -420,6 +422,106 @@ impl Schedule {
         Ok(iter)
     }
 
+    /// Returns the name of every system in this schedule along with the sets it was directly
+    /// added to, in the order the systems are executed.
+    ///
+    /// The list reflects the schedule as it was last built: systems added since then only show
+    /// up after the schedule is initialized or run again. The sets each function system is
+    /// automatically placed in, as well as anonymous sets, are left out.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    pub fn system_infos(&self) -> Result<Vec<SystemInfo>, ScheduleNotInitialized> {
+        let infos = self
+            .systems()?
+            .map(|(node_id, system)| {
+                let sets = self
+                    .graph
+                    .hierarchy
+                    .graph
+                    .neighbors_directed(node_id, Direction::Incoming)
+                    .filter(|set_id| set_id.is_set())
+                    .map(|set_id| self.graph.system_sets[set_id.index()].inner)
+                    .filter(|set| set.system_type().is_none() && !set.is_anonymous())
+                    .collect();
+
+                SystemInfo {
+                    name: system.name(),
+                    sets,
+                }
+            })
+            .collect();
+
+        Ok(infos)
+    }
+
+    /// Returns the [`NodeId`]s of the systems that belong to `set`, directly or through nested
+    /// sets.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    pub fn systems_in_set(
+        &self,
+        set: impl SystemSet,
+    ) -> Result<Vec<NodeId>, ScheduleNotInitialized> {
+        if !self.executor_initialized {
+            return Err(ScheduleNotInitialized);
+        }
+        let Some(&set_id) = self.graph.system_set_ids.get(&set.intern()) else {
+            return Ok(Vec::new());
+        };
+
+        let hierarchy = &self.graph.hierarchy.graph;
+        let mut dfs = Dfs::new(hierarchy, set_id);
+        let mut systems = Vec::new();
+        while let Some(node_id) = dfs.next(hierarchy) {
+            if node_id.is_system() {
+                systems.push(node_id);
+            }
+        }
+
+        Ok(systems)
+    }
+
+    /// Returns the [`NodeId`]s of the systems that are ordered to run after every system in `set`,
+    /// directly or transitively.
+    ///
+    /// Returns an empty list if `set` contains no systems. Like [`Schedule::system_infos`], this
+    /// reflects the schedule as it was last built.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    pub fn systems_ordered_after(
+        &self,
+        set: impl SystemSet,
+    ) -> Result<Vec<NodeId>, ScheduleNotInitialized> {
+        let members = self.systems_in_set(set)?;
+        let system_ids = &self.executable.system_ids;
+        let mut after: Option<FixedBitSet> = None;
+
+        for member in members {
+            let Some(start) = system_ids.iter().position(|id| *id == member) else {
+                continue;
+            };
+            let mut reachable = FixedBitSet::with_capacity(system_ids.len());
+            let mut stack = self.executable.system_dependents[start].clone();
+            while let Some(index) = stack.pop() {
+                if !reachable.put(index) {
+                    stack.extend_from_slice(&self.executable.system_dependents[index]);
+                }
+            }
+
+            match after.as_mut() {
+                Some(after) => after.intersect_with(&reachable),
+                None => after = Some(reachable),
+            }
+        }
+
+        Ok(after
+            .map(|after| after.ones().map(|index| system_ids[index]).collect())
+            .unwrap_or_default())
+    }
+
     /// Returns the number of systems in this schedule.
     pub fn systems_len(&self) -> usize {
         if !self.executor_initialized {
@@ -430,6 +532,16 @@ impl Schedule {
     }
 }
 
+/// The name of a system in a [`Schedule`] and the sets it belongs to, as returned by
+/// [`Schedule::system_infos`].
+#[derive(Clone, Debug)]
+pub struct SystemInfo {
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The sets the system was directly added to.
+    pub sets: Vec<InternedSystemSet>,
+}
+
 /// A directed acyclic graph structure.
 #[derive(Default)]
 pub struct Dag {
@@ -1075,6 +1187,9 @@ impl ScheduleGraph {
         // check that there are no edges to system-type sets that have multiple instances
         self.check_system_type_set_ambiguity(&set_systems)?;
 
+        // check for the same system being added more than once
+        self.optionally_check_duplicate_systems(schedule_label)?;
+
         let mut dependency_flattened = self.get_dependency_flattened(&set_systems);
 
         // modify graph with auto sync points
@@ -1885,6 +2000,56 @@ impl ScheduleGraph {
         }
     }
 
+    fn optionally_check_duplicate_systems(
+        &self,
+        schedule_label: InternedScheduleLabel,
+    ) -> Result<(), ScheduleBuildError> {
+        if self.settings.duplicate_system_detection == LogLevel::Ignore {
+            return Ok(());
+        }
+
+        let mut counts: HashMap<(TypeId, Cow<'static, str>), usize> = HashMap::new();
+        for system in self.systems.iter().filter_map(SystemNode::get) {
+            // `apply_deferred` is routinely added several times on purpose
+            if is_apply_deferred(system) {
+                continue;
+            }
+            // deref to use `System::type_id` instead of `Any::type_id`
+            let key = (system.as_ref().type_id(), system.name());
+            *counts.entry(key).or_default() += 1;
+        }
+
+        let mut duplicates: Vec<_> = counts.into_iter().filter(|(_, n)| *n > 1).collect();
+        if duplicates.is_empty() {
+            return Ok(());
+        }
+        duplicates.sort_by(|((_, a), _), ((_, b), _)| a.cmp(b));
+
+        let mut message = format!(
+            "{} systems were added more than once and will run once per instance. \
+            Remove the extra `add_systems` calls, or ignore this if the duplicates are intended:\n",
+            duplicates.len()
+        );
+        for ((_, name), count) in duplicates {
+            writeln!(message, " -- {name} ({count} instances)").unwrap();
+        }
+
+        match self.settings.duplicate_system_detection {
+            LogLevel::Ignore => Ok(()),
+            LogLevel::Warn => {
+                #[cfg(feature = "log")]
+                log::warn!(
+                    "Schedule {schedule_label:?} has duplicate systems.\n{}",
+                    message
+                );
+                #[cfg(not(feature = "log"))]
+                let _ = schedule_label;
+                Ok(())
+            }
+            LogLevel::Error => Err(ScheduleBuildError::DuplicateSystems(message)),
+        }
+    }
+
     fn get_conflicts_error_message(
         &self,
         ambiguities: &[(NodeId, NodeId, Vec<ComponentId>)],
@@ -1993,6 +2158,11 @@ pub enum ScheduleBuildError {
     /// This error is disabled by default, but can be opted-in using [`ScheduleBuildSettings`].
     #[error("Systems with conflicting access have indeterminate run order.\n{0}")]
     Ambiguity(String),
+    /// The same system was added to the schedule more than once.
+    ///
+    /// This error is disabled by default, but can be opted-in using [`ScheduleBuildSettings`].
+    #[error("Systems were added to the schedule more than once.\n{0}")]
+    DuplicateSystems(String),
     /// Tried to run a schedule before all of its systems have been initialized.
     #[error("Systems in schedule have not been initialized.")]
     Uninitialized,
@@ -2023,6 +2193,15 @@ pub struct ScheduleBuildSettings {
     ///
     /// Defaults to [`LogLevel::Warn`].
     pub hierarchy_detection: LogLevel,
+    /// Determines whether systems that were added more than once, i.e. instances with the same
+    /// type and name, are only logged or also result in a
+    /// [`DuplicateSystems`](ScheduleBuildError::DuplicateSystems) error.
+    ///
+    /// Generic systems may legitimately be added several times, so this is off by default.
+    /// [`apply_deferred`] is never reported.
+    ///
+    /// Defaults to [`LogLevel::Ignore`].
+    pub duplicate_system_detection: LogLevel,
     /// Auto insert [`apply_deferred`] systems into the schedule,
     /// when there are [`Deferred`](crate::prelude::Deferred)
     /// in one system and there are ordering dependencies on that system. [`Commands`](crate::system::Commands) is one
@@ -2056,6 +2235,7 @@ impl ScheduleBuildSettings {
         Self {
             ambiguity_detection: LogLevel::Ignore,
             hierarchy_detection: LogLevel::Warn,
+            duplicate_system_detection: LogLevel::Ignore,
             auto_insert_apply_deferred: true,
             use_shortnames: true,
             report_sets: true,
@@ -2194,6 +2374,79 @@ mod tests {
         assert_eq!(schedule.executable.systems.len(), 2);
     }
 
+    #[test]
+    fn system_infos_lists_names_and_sets() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Set;
+
+        fn in_set() {}
+        fn no_set() {}
+
+        let mut schedule = Schedule::default();
+        let mut world = World::default();
+        schedule.add_systems((in_set.in_set(Set), no_set).chain());
+        assert!(schedule.system_infos().is_err());
+
+        schedule.initialize(&mut world).unwrap();
+        let infos = schedule.system_infos().unwrap();
+        assert_eq!(infos.len(), 2);
+        assert!(infos[0].name.ends_with("in_set"));
+        assert_eq!(infos[0].sets, vec![Set.intern()]);
+        assert!(infos[1].name.ends_with("no_set"));
+        assert!(infos[1].sets.is_empty());
+    }
+
+    #[test]
+    fn systems_ordered_after_set() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Set;
+
+        fn first() {}
+        fn second() {}
+        fn after_set() {}
+        fn after_second() {}
+        fn unordered() {}
+
+        let mut schedule = Schedule::default();
+        let mut world = World::default();
+        schedule.add_systems((
+            (first, second).in_set(Set),
+            after_set.after(Set),
+            after_second.after(second),
+            unordered,
+        ));
+        schedule.initialize(&mut world).unwrap();
+
+        let name_of = |node_id| {
+            schedule
+                .systems()
+                .unwrap()
+                .find(|(id, _)| *id == node_id)
+                .map(|(_, system)| system.name())
+                .unwrap()
+        };
+
+        let mut in_set: Vec<_> = schedule
+            .systems_in_set(Set)
+            .unwrap()
+            .into_iter()
+            .map(name_of)
+            .collect();
+        in_set.sort();
+        assert_eq!(in_set.len(), 2);
+        assert!(in_set[0].ends_with("first"));
+        assert!(in_set[1].ends_with("second"));
+
+        let after: Vec<_> = schedule
+            .systems_ordered_after(Set)
+            .unwrap()
+            .into_iter()
+            .map(name_of)
+            .collect();
+        assert_eq!(after.len(), 1);
+        assert!(after[0].ends_with("after_set"));
+    }
+
     mod no_sync_edges {
         use super::*;
 
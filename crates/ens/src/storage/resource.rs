@@ -12,6 +12,11 @@ use ens_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
 
 use std::{cell::UnsafeCell, mem::ManuallyDrop, thread::ThreadId};
 
+#[cfg(feature = "change_detection")]
+use ens_utils::HashMap;
+#[cfg(feature = "change_detection")]
+use std::any::TypeId;
+
 /// The type-erased backing storage and metadata for a single resource within a [`World`].
 ///
 /// If `SEND` is false, values of this type will panic if dropped from a different thread.
@@ -302,11 +307,31 @@ impl<const SEND: bool> ResourceData<SEND> {
         }
     }
 
+    /// Overwrites the change ticks of the resource, if it exists.
+    ///
+    /// Returns `false` if the resource is not present.
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn set_ticks(&mut self, ticks: ComponentTicks) -> bool {
+        if !self.is_present() {
+            return false;
+        }
+        *self.added_ticks.get_mut() = ticks.added;
+        *self.changed_ticks.get_mut() = ticks.changed;
+        true
+    }
+
     #[cfg(feature = "change_detection")]
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         self.added_ticks.get_mut().check_tick(change_tick);
         self.changed_ticks.get_mut().check_tick(change_tick);
     }
+
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn max_change_age(&mut self, change_tick: Tick) -> u32 {
+        let added = change_tick.relative_to(*self.added_ticks.get_mut()).get();
+        let changed = change_tick.relative_to(*self.changed_ticks.get_mut()).get();
+        added.max(changed)
+    }
 }
 
 /// The backing store for all [`Resource`]s stored in the [`World`].
@@ -406,4 +431,64 @@ impl<const SEND: bool> Resources<SEND> {
             info.check_change_ticks(change_tick);
         }
     }
+
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn max_change_age(&mut self, change_tick: Tick) -> u32 {
+        self.resources
+            .values_mut()
+            .map(|info| info.max_change_age(change_tick))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The change ticks of every resource in a [`World`], captured by
+/// [`World::snapshot_resource_ticks`] and applied again by [`World::restore_resource_ticks`].
+///
+/// The [`ComponentTicks`] values are serializable with the `serialize` feature. The [`TypeId`]
+/// keys are only stable within a single build, so persisting a snapshot across builds requires
+/// mapping them to stable identifiers first.
+///
+/// [`World`]: crate::world::World
+/// [`World::snapshot_resource_ticks`]: crate::world::World::snapshot_resource_ticks
+/// [`World::restore_resource_ticks`]: crate::world::World::restore_resource_ticks
+#[cfg(feature = "change_detection")]
+#[derive(Clone, Debug, Default)]
+pub struct ResourceTicksSnapshot {
+    pub(crate) ticks: HashMap<TypeId, ComponentTicks>,
+}
+
+#[cfg(feature = "change_detection")]
+impl ResourceTicksSnapshot {
+    /// Returns the recorded ticks of the resource with the given [`TypeId`], if it was present.
+    #[inline]
+    pub fn get(&self, type_id: TypeId) -> Option<ComponentTicks> {
+        self.ticks.get(&type_id).copied()
+    }
+
+    /// Iterates over the recorded resources and their ticks.
+    pub fn iter(&self) -> impl Iterator<Item = (TypeId, ComponentTicks)> + '_ {
+        self.ticks.iter().map(|(type_id, ticks)| (*type_id, *ticks))
+    }
+
+    /// The number of resources in the snapshot.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    /// Returns `true` if the snapshot contains no resources.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+}
+
+#[cfg(feature = "change_detection")]
+impl FromIterator<(TypeId, ComponentTicks)> for ResourceTicksSnapshot {
+    fn from_iter<I: IntoIterator<Item = (TypeId, ComponentTicks)>>(iter: I) -> Self {
+        Self {
+            ticks: iter.into_iter().collect(),
+        }
+    }
 }
@@ -366,6 +366,11 @@ impl ComponentSparseSet {
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         self.dense.check_change_ticks(change_tick);
     }
+
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn max_change_age(&mut self, change_tick: Tick) -> u32 {
+        self.dense.max_change_age(change_tick)
+    }
 }
 
 /// A data structure that blends dense and sparse storage
@@ -653,12 +658,33 @@ impl SparseSets {
         }
     }
 
+    /// Clamps the change ticks of the sets whose [`ComponentId`] passes `filter`.
     #[cfg(feature = "change_detection")]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for set in self.sets.values_mut() {
-            set.check_change_ticks(change_tick);
+    pub(crate) fn check_change_ticks(
+        &mut self,
+        change_tick: Tick,
+        filter: impl Fn(ComponentId) -> bool,
+    ) {
+        for (id, set) in self.sets.iter_mut() {
+            if filter(*id) {
+                set.check_change_ticks(change_tick);
+            }
         }
     }
+
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn max_change_age(
+        &mut self,
+        change_tick: Tick,
+        filter: impl Fn(ComponentId) -> bool,
+    ) -> u32 {
+        self.sets
+            .iter_mut()
+            .filter(|(id, _)| filter(**id))
+            .map(|(_, set)| set.max_change_age(change_tick))
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 #[cfg(test)]
@@ -579,6 +579,18 @@ impl Column {
             component_ticks.get_mut().check_tick(change_tick);
         }
     }
+
+    /// Returns the age of the oldest change tick in this [`Column`] relative to `change_tick`,
+    /// or `0` if it is empty.
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn max_change_age(&mut self, change_tick: Tick) -> u32 {
+        self.added_ticks
+            .iter_mut()
+            .chain(self.changed_ticks.iter_mut())
+            .map(|component_ticks| change_tick.relative_to(*component_ticks.get_mut()).get())
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 /// A builder type for constructing [`Table`]s.
@@ -853,13 +865,34 @@ impl Table {
         self.entities.is_empty()
     }
 
+    /// Clamps the change ticks of the columns whose [`ComponentId`] passes `filter`.
     #[cfg(feature = "change_detection")]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        for column in self.columns.values_mut() {
-            column.check_change_ticks(change_tick);
+    pub(crate) fn check_change_ticks(
+        &mut self,
+        change_tick: Tick,
+        filter: impl Fn(ComponentId) -> bool,
+    ) {
+        for (id, column) in self.columns.iter_mut() {
+            if filter(*id) {
+                column.check_change_ticks(change_tick);
+            }
         }
     }
 
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn max_change_age(
+        &mut self,
+        change_tick: Tick,
+        filter: impl Fn(ComponentId) -> bool,
+    ) -> u32 {
+        self.columns
+            .iter_mut()
+            .filter(|(id, _)| filter(**id))
+            .map(|(_, column)| column.max_change_age(change_tick))
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Iterates over the [`Column`]s of the [`Table`].
     pub fn iter(&self) -> impl Iterator<Item = &Column> {
         self.columns.values()
@@ -974,11 +1007,28 @@ impl Tables {
     }
 
     #[cfg(feature = "change_detection")]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+    pub(crate) fn check_change_ticks(
+        &mut self,
+        change_tick: Tick,
+        filter: impl Fn(ComponentId) -> bool + Copy,
+    ) {
         for table in &mut self.tables {
-            table.check_change_ticks(change_tick);
+            table.check_change_ticks(change_tick, filter);
         }
     }
+
+    #[cfg(feature = "change_detection")]
+    pub(crate) fn max_change_age(
+        &mut self,
+        change_tick: Tick,
+        filter: impl Fn(ComponentId) -> bool + Copy,
+    ) -> u32 {
+        self.tables
+            .iter_mut()
+            .map(|table| table.max_change_age(change_tick, filter))
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 impl Index<TableId> for Tables {
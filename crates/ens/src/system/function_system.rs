@@ -515,6 +515,9 @@ where
 
     #[inline]
     unsafe fn run_unsafe(&mut self, input: Self::In, world: UnsafeWorldCell) -> Self::Out {
+        #[cfg(feature = "change_detection")]
+        let change_tick = world.increment_change_tick();
+
         // SAFETY:
         // - The caller has invoked `update_archetype_component_access`, which will panic
         //   if the world does not match.
@@ -526,14 +529,14 @@ where
                 &self.system_meta,
                 world,
                 #[cfg(feature = "change_detection")]
-                world.increment_change_tick(),
+                change_tick,
             )
         };
 
         #[cfg(feature = "change_detection")]
         {
             let out = self.func.run(input, params);
-            self.system_meta.last_run = world.change_tick();
+            self.system_meta.last_run = change_tick;
             out
         }
         #[cfg(not(feature = "change_detection"))]
@@ -39,6 +39,7 @@ use crate::component::ComponentHooks;
 use crate::{
     change_detection::TicksMut,
     component::{ComponentTicks, Tick},
+    storage::ResourceTicksSnapshot,
 };
 
 #[cfg(feature = "events")]
@@ -48,6 +49,8 @@ use crate::{
 };
 
 use ens_ptr::{OwningPtr, Ptr};
+#[cfg(feature = "change_detection")]
+use fixedbitset::FixedBitSet;
 #[cfg(feature = "log")]
 use log::warn;
 use std::{
@@ -134,6 +137,10 @@ pub struct World {
     pub(crate) last_change_tick: Tick,
     #[cfg(feature = "change_detection")]
     pub(crate) last_check_tick: Tick,
+    #[cfg(feature = "change_detection")]
+    strict_change_detection: bool,
+    #[cfg(feature = "change_detection")]
+    static_components: FixedBitSet,
 }
 
 impl Default for World {
@@ -157,6 +164,10 @@ impl Default for World {
             last_change_tick: Tick::new(0),
             #[cfg(feature = "change_detection")]
             last_check_tick: Tick::new(0),
+            #[cfg(feature = "change_detection")]
+            strict_change_detection: false,
+            #[cfg(feature = "change_detection")]
+            static_components: FixedBitSet::new(),
         }
     }
 }
@@ -1034,6 +1045,10 @@ impl World {
         #[cfg(feature = "change_detection")]
         {
             self.last_change_tick = self.increment_change_tick();
+
+            if self.strict_change_detection {
+                self.assert_change_tick_invariants();
+            }
         }
     }
 
@@ -1396,6 +1411,32 @@ impl World {
             .unwrap_or(false)
     }
 
+    /// Iterates over the [`TypeId`] and type name of every resource (including non-send resources)
+    /// currently present in the [`World`].
+    ///
+    /// Resources registered without a Rust type are skipped. This reflects the resources present
+    /// at the time of the call, and includes the ones used internally by the engine, such as
+    /// [`Schedules`].
+    pub fn iter_resource_type_names(&self) -> impl Iterator<Item = (TypeId, &'static str)> + '_ {
+        let resources = self
+            .storages
+            .resources
+            .iter()
+            .filter(|(_, data)| data.is_present())
+            .map(|(id, _)| id);
+        let non_send_resources = self
+            .storages
+            .non_send_resources
+            .iter()
+            .filter(|(_, data)| data.is_present())
+            .map(|(id, _)| id);
+
+        resources
+            .chain(non_send_resources)
+            .filter_map(|id| self.components.get_info(id))
+            .filter_map(|info| Some((info.type_id()?, info.static_name()?)))
+    }
+
     /// Retrieves the change ticks for the given resource.
     #[cfg(feature = "change_detection")]
     pub fn get_resource_change_ticks<R: Resource>(&self) -> Option<ComponentTicks> {
@@ -1418,6 +1459,53 @@ impl World {
             .and_then(|resource| resource.get_ticks())
     }
 
+    /// Captures the change ticks of every resource (including non-send resources) in the [`World`].
+    ///
+    /// This is intended for deterministic rollback: store the snapshot alongside the serialized
+    /// resource values, and pass it to [`World::restore_resource_ticks`] after restoring them so
+    /// that [`is_changed`](crate::change_detection::DetectChanges::is_changed) and friends behave
+    /// as they did when the snapshot was taken.
+    #[cfg(feature = "change_detection")]
+    pub fn snapshot_resource_ticks(&self) -> ResourceTicksSnapshot {
+        let resources = self
+            .storages
+            .resources
+            .iter()
+            .filter_map(|(id, data)| Some((id, data.get_ticks()?)));
+        let non_send_resources = self
+            .storages
+            .non_send_resources
+            .iter()
+            .filter_map(|(id, data)| Some((id, data.get_ticks()?)));
+
+        resources
+            .chain(non_send_resources)
+            .filter_map(|(id, ticks)| Some((self.components.get_info(id)?.type_id()?, ticks)))
+            .collect()
+    }
+
+    /// Overwrites the change ticks of every resource recorded in `snapshot` that is currently
+    /// present in the [`World`]. Resources missing from either side are left untouched.
+    ///
+    /// Restored ticks bypass change detection entirely: systems will observe additions and
+    /// changes relative to the restored ticks, not to what actually happened since. Only restore
+    /// a snapshot taken from this [`World`] (or a deterministic replica of it), and do not restore
+    /// snapshots older than [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE) ticks,
+    /// since [`World::check_change_ticks`] may have clamped the live ticks in the meantime.
+    #[cfg(feature = "change_detection")]
+    pub fn restore_resource_ticks(&mut self, snapshot: &ResourceTicksSnapshot) {
+        for (type_id, ticks) in snapshot.iter() {
+            let Some(component_id) = self.components.get_resource_id(type_id) else {
+                continue;
+            };
+            if let Some(data) = self.storages.resources.get_mut(component_id) {
+                data.set_ticks(ticks);
+            } else if let Some(data) = self.storages.non_send_resources.get_mut(component_id) {
+                data.set_ticks(ticks);
+            }
+        }
+    }
+
     /// Gets a reference to the resource of the given type
     ///
     /// # Panics
@@ -2308,8 +2396,10 @@ impl World {
             ref mut non_send_resources,
         } = self.storages;
 
-        tables.check_change_ticks(change_tick);
-        sparse_sets.check_change_ticks(change_tick);
+        let static_components = &self.static_components;
+        let is_dynamic = |id: ComponentId| !static_components.contains(id.index());
+        tables.check_change_ticks(change_tick, is_dynamic);
+        sparse_sets.check_change_ticks(change_tick, is_dynamic);
         resources.check_change_ticks(change_tick);
         non_send_resources.check_change_ticks(change_tick);
 
@@ -2320,6 +2410,137 @@ impl World {
         self.last_check_tick = change_tick;
     }
 
+    /// Marks the component `C` as static, excluding it from the change tick scans of
+    /// [`World::check_change_ticks`] and [`World::assert_change_tick_invariants`].
+    ///
+    /// In large, mostly static scenes most of the time spent in these scans goes to components
+    /// that never change. Since the ticks of static components are no longer clamped, they would
+    /// eventually overflow, so static components don't report changes until they are unmarked
+    /// with [`World::unmark_static`]: [`Added<C>`](crate::query::Added) and
+    /// [`Changed<C>`](crate::query::Changed) don't match, and [`Ref<C>`](crate::access::Ref)
+    /// never reports `C` as added or changed. Mutations are still recorded, so those made within
+    /// [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE) ticks of unmarking `C` are
+    /// reported afterwards.
+    #[cfg(feature = "change_detection")]
+    pub fn mark_static<C: Component>(&mut self) {
+        let id = self.init_component::<C>();
+        self.static_components.grow(id.index() + 1);
+        self.static_components.insert(id.index());
+    }
+
+    /// Reverts [`World::mark_static`] for the component `C`.
+    ///
+    /// The change ticks of `C` are clamped immediately, so change detection is reliable again
+    /// from this point on.
+    #[cfg(feature = "change_detection")]
+    pub fn unmark_static<C: Component>(&mut self) {
+        let Some(id) = self.components.component_id::<C>() else {
+            return;
+        };
+        if !self.static_components.contains(id.index()) {
+            return;
+        }
+        self.static_components.set(id.index(), false);
+
+        let change_tick = self.change_tick();
+        let Storages {
+            ref mut tables,
+            ref mut sparse_sets,
+            ..
+        } = self.storages;
+        tables.check_change_ticks(change_tick, |other| other == id);
+        sparse_sets.check_change_ticks(change_tick, |other| other == id);
+    }
+
+    /// Returns `true` if the component `C` was marked with [`World::mark_static`].
+    #[cfg(feature = "change_detection")]
+    pub fn is_static<C: Component>(&self) -> bool {
+        self.components
+            .component_id::<C>()
+            .is_some_and(|id| self.static_components.contains(id.index()))
+    }
+
+    /// Enables or disables strict change detection.
+    ///
+    /// While enabled, [`World::clear_trackers`] calls [`World::assert_change_tick_invariants`]
+    /// at the end of every update. This scans every change tick in the [`World`], so it is meant
+    /// to harden tests and development builds rather than for use in production.
+    #[cfg(feature = "change_detection")]
+    pub fn set_strict_change_detection(&mut self, strict: bool) {
+        self.strict_change_detection = strict;
+    }
+
+    /// Returns `true` if [strict change detection](World::set_strict_change_detection) is enabled.
+    #[cfg(feature = "change_detection")]
+    pub fn is_strict_change_detection(&self) -> bool {
+        self.strict_change_detection
+    }
+
+    /// Asserts that the change ticks of this [`World`] are consistent.
+    ///
+    /// This checks that [`World::check_change_ticks`] has scanned the [`World`] recently enough
+    /// for ticks to never overflow, and that no component or resource tick is older than the
+    /// last scan allows (which would indicate a tick from the future or a missed clamp).
+    ///
+    /// This scans every change tick in the [`World`], see
+    /// [`World::set_strict_change_detection`].
+    ///
+    /// # Panics
+    ///
+    /// Panics with a description of the violated invariant if the change ticks are inconsistent.
+    #[cfg(feature = "change_detection")]
+    pub fn assert_change_tick_invariants(&mut self) {
+        use crate::change_detection::MAX_CHANGE_AGE;
+
+        let change_tick = self.change_tick();
+        let ticks_since_check = change_tick.relative_to(self.last_check_tick).get();
+        assert!(
+            ticks_since_check < 2 * CHECK_TICK_THRESHOLD,
+            "change ticks were last checked {ticks_since_check} ticks ago, which exceeds the \
+            maximum of {} ticks between `World::check_change_ticks` scans",
+            2 * CHECK_TICK_THRESHOLD - 1,
+        );
+
+        // Every tick was clamped to at most `MAX_CHANGE_AGE` during the last scan and has aged
+        // by `ticks_since_check` since then.
+        let max_age = MAX_CHANGE_AGE + ticks_since_check;
+
+        let last_change_age = change_tick.relative_to(self.last_change_tick).get();
+        assert!(
+            last_change_age <= max_age,
+            "the last change tick of the world is {last_change_age} ticks old, which exceeds \
+            the maximum age of {max_age} ticks",
+        );
+
+        let Storages {
+            ref mut tables,
+            ref mut sparse_sets,
+            ref mut resources,
+            ref mut non_send_resources,
+        } = self.storages;
+
+        let static_components = &self.static_components;
+        let is_dynamic = |id: ComponentId| !static_components.contains(id.index());
+        for (storage, age) in [
+            ("table", tables.max_change_age(change_tick, is_dynamic)),
+            (
+                "sparse set",
+                sparse_sets.max_change_age(change_tick, is_dynamic),
+            ),
+            ("resource", resources.max_change_age(change_tick)),
+            (
+                "non-send resource",
+                non_send_resources.max_change_age(change_tick),
+            ),
+        ] {
+            assert!(
+                age <= max_age,
+                "a {storage} change tick is {age} ticks old, which exceeds the maximum age of \
+                {max_age} ticks since the last `World::check_change_ticks` scan",
+            );
+        }
+    }
+
     /// Runs both [`clear_entities`](Self::clear_entities) and [`clear_resources`](Self::clear_resources),
     /// invalidating all [`Entity`] and resource fetches such as [`Res`], [`ResMut`](crate::system::ResMut)
     pub fn clear_all(&mut self) {
@@ -2841,6 +3062,54 @@ mod tests {
         assert_eq!(resource.0, 43);
     }
 
+    #[test]
+    fn iter_resource_type_names_lists_present_resources() {
+        #[derive(Resource)]
+        struct Removed;
+
+        let mut world = World::new();
+        world.insert_resource(TestResource(1));
+        world.insert_resource(Removed);
+        world.remove_resource::<Removed>();
+
+        let resources: Vec<_> = world.iter_resource_type_names().collect();
+        assert_eq!(
+            resources,
+            [(
+                TypeId::of::<TestResource>(),
+                std::any::type_name::<TestResource>()
+            )]
+        );
+    }
+
+    #[cfg(feature = "change_detection")]
+    #[test]
+    fn snapshot_and_restore_resource_ticks() {
+        let mut world = World::new();
+        world.insert_resource(TestResource(0));
+        world.clear_trackers();
+
+        let snapshot = world.snapshot_resource_ticks();
+        assert_eq!(snapshot.len(), 1);
+        assert!(!world.is_resource_changed::<TestResource>());
+
+        world.resource_mut::<TestResource>().0 += 1;
+        assert!(world.is_resource_changed::<TestResource>());
+
+        world.restore_resource_ticks(&snapshot);
+        assert!(!world.is_resource_changed::<TestResource>());
+        assert_eq!(
+            world
+                .get_resource_change_ticks::<TestResource>()
+                .unwrap()
+                .last_changed_tick(),
+            snapshot
+                .get(TypeId::of::<TestResource>())
+                .unwrap()
+                .last_changed_tick()
+        );
+    }
+
     #[test]
     fn custom_resource_with_layout() {
         static DROP_COUNT: AtomicU32 = AtomicU32::new(0);
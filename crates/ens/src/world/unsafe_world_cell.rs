@@ -233,6 +233,17 @@ impl<'w> UnsafeWorldCell<'w> {
         &unsafe { self.world_metadata() }.components
     }
 
+    /// Returns `true` if the component was marked with [`World::mark_static`].
+    #[cfg(feature = "change_detection")]
+    #[inline]
+    pub(crate) fn is_static_component(self, id: ComponentId) -> bool {
+        // SAFETY:
+        // - we only access world metadata
+        unsafe { self.world_metadata() }
+            .static_components
+            .contains(id.index())
+    }
+
     /// Retrieves this world's collection of [removed components](RemovedComponentEvents).
     #[cfg(feature = "events")]
     pub fn removed_components(self) -> &'w RemovedComponentEvents {
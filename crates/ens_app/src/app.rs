@@ -1,21 +1,27 @@
-use crate::{Main, MainSchedulePlugin, Plugin, Plugins};
+use crate::{Main, MainScheduleOrder, MainSchedulePlugin, Plugin, Plugins};
 
 #[cfg(feature = "events")]
-use crate::PreUpdate;
+use crate::{PreUpdate, Shutdown};
 
 #[cfg(feature = "states")]
 use crate::StateTransition;
 
 use ens::{
     prelude::*,
-    schedule::{InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel},
+    schedule::{InternedScheduleLabel, LogLevel, ScheduleBuildSettings, ScheduleLabel, SystemInfo},
 };
 
+#[cfg(feature = "events")]
+use ens::event::{Events, ManualEventReader};
+
 use ens_utils::{intern::Interned, label::DynEq, HashMap, HashSet};
 
 use std::{
     fmt::Debug,
+    num::NonZeroU8,
     panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    process::{ExitCode, Termination},
+    time::{Duration, Instant},
 };
 use thiserror::Error;
 
@@ -55,12 +61,15 @@ pub struct App {
     /// The main ECS [`World`] of the [`App`].
     /// This stores and provides access to all the main data of the application.
     /// The systems of the [`App`] will run using this [`World`].
+    ///
+    /// Use [`App::world`] and [`App::world_mut`] instead, which will keep working if this field
+    /// is made private.
     pub world: World,
     /// The [runner function](Self::set_runner) is primarily responsible for managing
     /// the application's event loop and advancing the [`Schedule`].
     /// Typically, it is not configured manually, but set by one of Bevy's built-in plugins.
     /// See [`ScheduleRunnerPlugin`](crate::schedule_runner::ScheduleRunnerPlugin).
-    pub runner: Box<dyn FnOnce(App) + Send>, // Send bound is required to make App Send
+    pub runner: Box<dyn FnOnce(App) -> AppExit + Send>, // Send bound is required to make App Send
     /// The schedule that systems are added to by default.
     ///
     /// The schedule that runs the main loop of schedule execution.
@@ -69,9 +78,22 @@ pub struct App {
     pub main_schedule_label: InternedScheduleLabel,
     plugin_registry: Vec<Box<dyn Plugin>>,
     plugin_name_added: HashSet<Box<str>>,
+    plugin_build_order: Vec<PluginBuildRecord>,
     /// A private counter to prevent incorrect calls to `App::run()` from `Plugin::build()`
     building_plugin_depth: usize,
     plugins_state: PluginsState,
+    frame_callbacks: Option<FrameCallbacks>,
+    #[cfg(feature = "sub_app")]
+    sub_apps: Vec<(crate::InternedAppLabel, crate::SubApp)>,
+    /// Tracks the [`AppExit`] events already seen by [`App::run_until_exit`].
+    #[cfg(feature = "events")]
+    app_exit_reader: ManualEventReader<AppExit>,
+}
+
+/// The callbacks set by [`App::set_frame_callbacks`], run before and after the main schedule.
+struct FrameCallbacks {
+    begin: Box<dyn FnMut() + Send>,
+    end: Box<dyn FnMut() + Send>,
 }
 
 impl Debug for App {
@@ -88,6 +110,8 @@ impl Default for App {
 
         #[cfg(feature = "events")]
         app.add_event::<AppExit>();
+        #[cfg(feature = "events")]
+        app.add_event::<AppShutdown>();
 
         app
     }
@@ -106,6 +130,29 @@ pub enum PluginsState {
     Cleaned,
 }
 
+/// A record of a [`Plugin`] that was added to an [`App`], see [`App::plugin_build_order`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct PluginBuildRecord {
+    /// The [name](Plugin::name) of the plugin.
+    pub name: String,
+    /// The crate the plugin originates from, derived from the plugin's name.
+    pub crate_name: String,
+    /// Whether the plugin was built, `false` if it was disabled in its
+    /// [`PluginGroup`](crate::PluginGroup).
+    pub enabled: bool,
+}
+
+impl PluginBuildRecord {
+    fn new(name: &str, enabled: bool) -> Self {
+        let crate_name = name.split_once("::").map_or(name, |(krate, _)| krate);
+        Self {
+            name: name.to_string(),
+            crate_name: crate_name.to_string(),
+            enabled,
+        }
+    }
+}
+
 // Dummy plugin used to temporary hold the place in the plugin registry
 struct PlaceholderPlugin;
 
@@ -131,25 +178,95 @@ impl App {
             runner: Box::new(run_once),
             plugin_registry: Vec::default(),
             plugin_name_added: Default::default(),
+            plugin_build_order: Vec::default(),
             main_schedule_label: Main.intern(),
             building_plugin_depth: 0,
             plugins_state: PluginsState::Adding,
+            frame_callbacks: None,
+            #[cfg(feature = "sub_app")]
+            sub_apps: Vec::new(),
+            #[cfg(feature = "events")]
+            app_exit_reader: ManualEventReader::default(),
         }
     }
 
+    /// Returns a reference to the main [`World`] of this [`App`].
+    ///
+    /// Prefer this over the [`world`](App#structfield.world) field, which may stop being public
+    /// in the future.
+    #[inline]
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Returns a mutable reference to the main [`World`] of this [`App`].
+    ///
+    /// Prefer this over the [`world`](App#structfield.world) field, which may stop being public
+    /// in the future.
+    #[inline]
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
     /// Advances the execution of the [`Schedule`] by one cycle.
     ///
     /// The schedule run by this method is determined by the [`main_schedule_label`](App) field.
     /// By default this is [`Main`].
     ///
+    /// This is [`App::update_without_clearing_trackers`] followed by [`App::clear_trackers`].
+    ///
     /// # Panics
     ///
     /// The active schedule of the app must be set before this method is called.
     #[inline(always)]
     pub fn update(&mut self) {
-        self.world.run_schedule(self.main_schedule_label);
+        self.update_without_clearing_trackers();
+        self.clear_trackers();
+    }
 
-        self.world.clear_trackers();
+    /// Runs the main schedule once like [`App::update`], but leaves change trackers alone, so
+    /// that several calls are seen as one update until [`App::clear_trackers`] is called.
+    ///
+    /// This is meant for embedders that run sub-steps, such as a stepping debugger. Keep in mind
+    /// what is and isn't deferred:
+    /// - Systems always detect changes made since they last ran, whether or not trackers were
+    ///   cleared in between, so system-level change detection is unaffected.
+    /// - Change detection outside of systems, such as [`World::is_resource_changed`] or
+    ///   [`World::query`] with [`Ref`](ens::access::Ref), reports every change since the last
+    ///   [`App::clear_trackers`] instead of since the last update.
+    /// - Removed components keep accumulating, and [`RemovedComponents`] readers see them until
+    ///   two clears have happened, so skipping clears for long grows that buffer.
+    ///
+    /// # Panics
+    ///
+    /// The active schedule of the app must be set before this method is called.
+    ///
+    /// [`RemovedComponents`]: ens::removal_detection::RemovedComponents
+    #[inline]
+    pub fn update_without_clearing_trackers(&mut self) {
+        if let Some(callbacks) = &mut self.frame_callbacks {
+            (callbacks.begin)();
+        }
+        let main_schedule_label = self.main_schedule_label;
+        self.world_mut().run_schedule(main_schedule_label);
+        #[cfg(feature = "sub_app")]
+        for (_, sub_app) in &mut self.sub_apps {
+            // The field is borrowed directly, as the sub-apps are borrowed at the same time
+            sub_app.run(&mut self.world);
+        }
+        if let Some(callbacks) = &mut self.frame_callbacks {
+            (callbacks.end)();
+        }
+    }
+
+    /// Ends the current update as far as change detection is concerned, by calling
+    /// [`World::clear_trackers`].
+    ///
+    /// [`App::update`] does this after every run of the main schedule. Only call this yourself
+    /// when using [`App::update_without_clearing_trackers`].
+    #[inline]
+    pub fn clear_trackers(&mut self) {
+        self.world_mut().clear_trackers();
     }
 
     /// Starts the application by calling the app's [runner function](Self::set_runner).
@@ -172,17 +289,87 @@ impl App {
     ///
     /// By default, *Bevy* uses the `winit` crate for window creation.
     ///
+    /// The [`Uptime`] resource is inserted right before the runner is called.
+    ///
     /// # Panics
     ///
     /// Panics if called from `Plugin::build()`, because it would prevent other plugins to properly build.
     pub fn run(&mut self) {
+        self.run_return();
+    }
+
+    /// Like [`App::run`], but returns the [`AppExit`] the [runner function](Self::set_runner)
+    /// finished with, e.g. for integration tests or command line tools that need to report how the
+    /// app ended.
+    ///
+    /// [`ScheduleRunnerPlugin`](crate::ScheduleRunnerPlugin) returns the last [`AppExit`] event
+    /// sent in the update that stopped the loop, so an [`AppExit::Error`] carries its exit code
+    /// out. Runners that don't produce one, such as the default runner when no [`AppExit`] was
+    /// sent, return [`AppExit::Success`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from `Plugin::build()`, because it would prevent other plugins to properly build.
+    pub fn run_return(&mut self) -> AppExit {
         let mut app = std::mem::replace(self, App::empty());
         if app.building_plugin_depth > 0 {
             panic!("App::run() was called from within Plugin::build(), which is not allowed.");
         }
 
+        app.world_mut().insert_resource(Uptime::now());
+
         let runner = std::mem::replace(&mut app.runner, Box::new(run_once));
-        runner(app);
+        runner(app)
+    }
+
+    /// Repeatedly calls [`App::update`] until an [`AppExit`] event is sent, without going through
+    /// the app's [runner function](Self::set_runner), and returns that event.
+    ///
+    /// This is meant for hosts such as editors that drive the [`App`] themselves but still want
+    /// the exit semantics of [`ScheduleRunnerPlugin`](crate::ScheduleRunnerPlugin): the loop stops
+    /// right after the update in which the event was sent. Plugins are finished and cleaned up
+    /// first if that has not happened yet.
+    ///
+    /// This blocks the calling thread until an [`AppExit`] is sent, so it never returns if
+    /// nothing sends one. Unlike [`App::run`], the [`App`] stays usable afterwards, and calling
+    /// this again waits for a new [`AppExit`].
+    ///
+    /// Like [`App::run`], this inserts the [`Uptime`] resource, unless it is already present.
+    #[cfg(feature = "events")]
+    pub fn run_until_exit(&mut self) -> AppExit {
+        if !self.world().contains_resource::<Uptime>() {
+            self.world_mut().insert_resource(Uptime::now());
+        }
+
+        if self.plugins_state() != PluginsState::Cleaned {
+            while self.plugins_state() == PluginsState::Adding {
+                ens_tasks::tick_global_task_pools_on_main_thread();
+            }
+            self.finish();
+            self.cleanup();
+        }
+
+        loop {
+            self.update();
+            // The field is borrowed directly, as the reader is borrowed at the same time
+            if let Some(app_exit_events) = self.world.get_resource::<Events<AppExit>>() {
+                if let Some(&exit) = self.app_exit_reader.read(app_exit_events).last() {
+                    self.shutdown();
+                    return exit;
+                }
+            }
+        }
+    }
+
+    /// Returns how long the app has been running, measured from when [`App::run`] (or
+    /// [`App::run_until_exit`]) started it.
+    ///
+    /// Returns [`Duration::ZERO`] if the app has not been started yet. Since [`App::run`] may never
+    /// return, systems should read the [`Uptime`] resource instead.
+    pub fn uptime(&self) -> Duration {
+        self.world()
+            .get_resource::<Uptime>()
+            .map_or(Duration::ZERO, Uptime::elapsed)
     }
 
     /// Check the state of all plugins already added to this app. This is usually called by the
@@ -216,6 +403,11 @@ impl App {
 
     /// Run [`Plugin::cleanup`] for each plugin. This is usually called by the event loop after
     /// [`App::finish`], but can be useful for situations where you want to use [`App::update`].
+    ///
+    /// Plugins are cleaned up in the order they were added in. Despite its name, this is the
+    /// last step of setup and runs before the first update, not when the app exits: plugins use it
+    /// to move resources other plugins needed while building, so it has to see them in build
+    /// order. Teardown happens in [`Plugin::shutdown`] instead, see [`App::shutdown`].
     pub fn cleanup(&mut self) {
         // temporarily remove the plugin registry to run each plugin's setup function on app.
         let plugin_registry = std::mem::take(&mut self.plugin_registry);
@@ -226,6 +418,103 @@ impl App {
         self.plugins_state = PluginsState::Cleaned;
     }
 
+    /// Tears the app down: sends an [`AppShutdown`] event, runs the [`Shutdown`] schedule once
+    /// if it exists, then runs [`Plugin::shutdown`] for each plugin.
+    ///
+    /// Plugins are shut down in the reverse of the order they were added in, so teardown mirrors
+    /// setup: a plugin is shut down before the plugins it was built after.
+    ///
+    /// The [`ScheduleRunnerPlugin`](crate::ScheduleRunnerPlugin) runner and
+    /// [`App::run_until_exit`] call this after the update in which an [`AppExit`] was sent, right
+    /// before they return. Custom runners should do the same.
+    #[cfg(feature = "events")]
+    pub fn shutdown(&mut self) {
+        if self.world().contains_resource::<Events<AppShutdown>>() {
+            self.world_mut().send_event(AppShutdown);
+        }
+        let _ = self.world_mut().try_run_schedule(Shutdown);
+
+        // temporarily remove the plugin registry to run each plugin's teardown function on app.
+        let plugin_registry = std::mem::take(&mut self.plugin_registry);
+        for plugin in plugin_registry.iter().rev() {
+            plugin.shutdown(self);
+        }
+        self.plugin_registry = plugin_registry;
+    }
+
+    /// Runs `f` on every [`World`] owned by this [`App`].
+    ///
+    /// `f` is called with the main [`World`] first, then with the world of each
+    /// [sub-app](Self::insert_sub_app) in insertion order. Library code can use this to write
+    /// world-maintenance passes without assuming that the [`App`] only owns a single [`World`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ens_app::prelude::*;
+    /// # use ens::prelude::*;
+    /// #
+    /// let mut app = App::new();
+    /// app.for_each_world(|world| {
+    ///     world.spawn_empty();
+    /// });
+    /// assert_eq!(app.world().entities().len(), 1);
+    /// ```
+    pub fn for_each_world(&mut self, mut f: impl FnMut(&mut World)) -> &mut Self {
+        f(self.world_mut());
+        #[cfg(feature = "sub_app")]
+        for (_, sub_app) in &mut self.sub_apps {
+            f(&mut sub_app.world);
+        }
+        self
+    }
+
+    /// Inserts a [`SubApp`](crate::SubApp) with the given `label`, replacing any sub-app with the
+    /// same label.
+    ///
+    /// Sub-apps are run in insertion order by [`App::update`], right after the main schedule.
+    #[cfg(feature = "sub_app")]
+    pub fn insert_sub_app(
+        &mut self,
+        label: impl crate::AppLabel,
+        sub_app: crate::SubApp,
+    ) -> &mut Self {
+        let label = label.intern();
+        match self.sub_apps.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, existing)) => *existing = sub_app,
+            None => self.sub_apps.push((label, sub_app)),
+        }
+        self
+    }
+
+    /// Removes the [`SubApp`](crate::SubApp) with the given `label`, if it exists.
+    #[cfg(feature = "sub_app")]
+    pub fn remove_sub_app(&mut self, label: impl crate::AppLabel) -> Option<crate::SubApp> {
+        let label = label.intern();
+        let index = self.sub_apps.iter().position(|(l, _)| *l == label)?;
+        Some(self.sub_apps.remove(index).1)
+    }
+
+    /// Returns the [`SubApp`](crate::SubApp) with the given `label`, if it exists.
+    #[cfg(feature = "sub_app")]
+    pub fn get_sub_app(&self, label: impl crate::AppLabel) -> Option<&crate::SubApp> {
+        let label = label.intern();
+        self.sub_apps
+            .iter()
+            .find(|(l, _)| *l == label)
+            .map(|(_, sub_app)| sub_app)
+    }
+
+    /// Returns the [`SubApp`](crate::SubApp) with the given `label` mutably, if it exists.
+    #[cfg(feature = "sub_app")]
+    pub fn get_sub_app_mut(&mut self, label: impl crate::AppLabel) -> Option<&mut crate::SubApp> {
+        let label = label.intern();
+        self.sub_apps
+            .iter_mut()
+            .find(|(l, _)| *l == label)
+            .map(|(_, sub_app)| sub_app)
+    }
+
     /// Adds a system to the given schedule in this app's [`Schedules`].
     ///
     /// # Examples
@@ -249,12 +538,60 @@ impl App {
         systems: impl IntoSystemConfigs<M>,
     ) -> &mut Self {
         let schedule = schedule.intern();
-        let mut schedules = self.world.resource_mut::<Schedules>();
+        let mut schedules = self.world_mut().resource_mut::<Schedules>();
+
+        if let Some(schedule) = schedules.get_mut(schedule) {
+            schedule.add_systems(systems);
+        } else {
+            let mut new_schedule = Schedule::new(schedule);
+            new_schedule.add_systems(systems);
+            schedules.insert(new_schedule);
+        }
+
+        self
+    }
+
+    /// Adds systems to the given schedule, first creating it and applying `configure` to it if it
+    /// does not exist yet.
+    ///
+    /// `configure` is only called when the schedule is created, so it is the place for settings
+    /// such as the executor kind or build settings. If the schedule already exists, this behaves
+    /// like [`App::add_systems`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ens_app::prelude::*;
+    /// # use ens::{prelude::*, schedule::{ExecutorKind, ScheduleLabel}};
+    /// #
+    /// # let mut app = App::new();
+    /// # fn system_a() {}
+    /// # fn system_b() {}
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct Simulation;
+    ///
+    /// app.add_systems_to_new(
+    ///     Simulation,
+    ///     |schedule| {
+    ///         schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    ///     },
+    ///     (system_a, system_b),
+    /// );
+    /// ```
+    pub fn add_systems_to_new<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        configure: impl FnOnce(&mut Schedule),
+        systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self {
+        let schedule = schedule.intern();
+        let mut schedules = self.world_mut().resource_mut::<Schedules>();
 
         if let Some(schedule) = schedules.get_mut(schedule) {
             schedule.add_systems(systems);
         } else {
             let mut new_schedule = Schedule::new(schedule);
+            configure(&mut new_schedule);
             new_schedule.add_systems(systems);
             schedules.insert(new_schedule);
         }
@@ -270,7 +607,7 @@ impl App {
         sets: impl IntoSystemSetConfigs,
     ) -> &mut Self {
         let schedule = schedule.intern();
-        let mut schedules = self.world.resource_mut::<Schedules>();
+        let mut schedules = self.world_mut().resource_mut::<Schedules>();
         if let Some(schedule) = schedules.get_mut(schedule) {
             schedule.configure_sets(sets);
         } else {
@@ -307,7 +644,7 @@ impl App {
     where
         T: Event,
     {
-        if !self.world.contains_resource::<Events<T>>() {
+        if !self.world().contains_resource::<Events<T>>() {
             self.init_resource::<Events<T>>().add_systems(
                 PreUpdate,
                 ens::event::event_update_system::<T>
@@ -318,6 +655,126 @@ impl App {
         self
     }
 
+    /// Like [`App::add_event`], but stores at most `max_per_frame` events of type `T` per update.
+    ///
+    /// Events sent past the cap are dropped and counted, see [`App::dropped_events`]. Readers
+    /// never see dropped events, so only cap events that are safe to lose. This is meant to keep
+    /// a flood of events, such as from a misbehaving client, from growing the event buffers
+    /// without bound. See [`Events::set_max_per_frame`].
+    #[cfg(feature = "events")]
+    pub fn add_event_capped<T>(&mut self, max_per_frame: usize) -> &mut Self
+    where
+        T: Event,
+    {
+        self.add_event::<T>();
+        self.world_mut()
+            .resource_mut::<Events<T>>()
+            .set_max_per_frame(Some(max_per_frame));
+        self
+    }
+
+    /// Returns how many events of type `T` were dropped because of the cap set with
+    /// [`App::add_event_capped`], or `0` if the event was never added.
+    #[cfg(feature = "events")]
+    pub fn dropped_events<T>(&self) -> usize
+    where
+        T: Event,
+    {
+        self.world()
+            .get_resource::<Events<T>>()
+            .map_or(0, Events::dropped_count)
+    }
+
+    /// Sends a [`ResourceChanged<R>`](crate::ResourceChanged) event carrying the old and new
+    /// values whenever the resource `R` changes, registering the event if needed.
+    ///
+    /// The check runs in [`PostUpdate`](crate::PostUpdate) and compares against the value seen by
+    /// the previous check, so a resource that is mutated but ends up equal to its old value sends
+    /// nothing, and several changes within one frame are reported as a single event. Changes made
+    /// later in [`PostUpdate`](crate::PostUpdate) are reported in the next frame.
+    ///
+    /// Call this once per resource type; each call adds another system sending the events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ens_app::{prelude::*, ResourceChanged};
+    /// # use ens::{event::EventReader, prelude::*};
+    /// #
+    /// #[derive(Resource, Clone, PartialEq, Debug)]
+    /// struct Score(u32);
+    ///
+    /// fn log_score(mut changes: EventReader<ResourceChanged<Score>>) {
+    ///     for change in changes.read() {
+    ///         println!("score went from {:?} to {:?}", change.old, change.new);
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// app.insert_resource(Score(0))
+    ///     .emit_change_events::<Score>()
+    ///     .add_systems(Update, log_score);
+    /// ```
+    #[cfg(all(feature = "events", feature = "change_detection"))]
+    pub fn emit_change_events<R>(&mut self) -> &mut Self
+    where
+        R: Resource + Clone + PartialEq,
+    {
+        self.add_event::<crate::ResourceChanged<R>>()
+            .add_systems(crate::PostUpdate, crate::emit_resource_changed::<R>)
+    }
+
+    /// Adds systems to the [`StartupStage`](crate::StartupStage) with the given index, registering
+    /// the stage if needed.
+    ///
+    /// Stages run once, in index order, during the first run of the [`Main`] schedule, between
+    /// [`Startup`](crate::Startup) and [`PostStartup`](crate::PostStartup). After each stage,
+    /// [`StartupProgress`](crate::StartupProgress) is updated and the callbacks added with
+    /// [`App::on_startup_progress`] are called, so a loading screen can report progress.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ens_app::prelude::*;
+    /// #
+    /// # fn load_textures() {}
+    /// # fn load_levels() {}
+    /// let mut app = App::new();
+    /// app.add_startup_stage_systems(0, load_textures)
+    ///     .add_startup_stage_systems(1, load_levels)
+    ///     .on_startup_progress(|_world, progress| {
+    ///         println!("Loading: stage {}/{}", progress.completed, progress.total);
+    ///     });
+    /// ```
+    #[cfg(feature = "startup")]
+    pub fn add_startup_stage_systems<M>(
+        &mut self,
+        stage: usize,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self {
+        self.world_mut()
+            .resource_mut::<crate::StartupStages>()
+            .stages
+            .insert(stage);
+        self.add_systems(crate::StartupStage(stage), systems)
+    }
+
+    /// Adds a callback called after each [`StartupStage`](crate::StartupStage) finishes, with the
+    /// updated [`StartupProgress`](crate::StartupProgress).
+    ///
+    /// See [`App::add_startup_stage_systems`].
+    #[cfg(feature = "startup")]
+    pub fn on_startup_progress(
+        &mut self,
+        callback: impl FnMut(&mut World, crate::StartupProgress) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .resource_mut::<crate::StartupStages>()
+            .callbacks
+            .push(Box::new(callback));
+        self
+    }
+
     /// Inserts a [`Resource`] to the current [`App`] and overwrites any [`Resource`] previously added of the same type.
     ///
     /// A [`Resource`] in Bevy represents globally unique data. [`Resource`]s must be added to Bevy apps
@@ -340,7 +797,7 @@ impl App {
     ///    .insert_resource(MyCounter { counter: 0 });
     /// ```
     pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
-        self.world.insert_resource(resource);
+        self.world_mut().insert_resource(resource);
         self
     }
 
@@ -364,7 +821,7 @@ impl App {
     /// ```
     #[cfg(feature = "non_send")]
     pub fn insert_non_send_resource<R: 'static>(&mut self, resource: R) -> &mut Self {
-        self.world.insert_non_send_resource(resource);
+        self.world_mut().insert_non_send_resource(resource);
         self
     }
 
@@ -399,7 +856,7 @@ impl App {
     ///     .init_resource::<MyCounter>();
     /// ```
     pub fn init_resource<R: Resource + FromWorld>(&mut self) -> &mut Self {
-        self.world.init_resource::<R>();
+        self.world_mut().init_resource::<R>();
         self
     }
 
@@ -410,16 +867,32 @@ impl App {
     /// the [`Default::default`] method to initialize the [`Resource`].
     #[cfg(feature = "non_send")]
     pub fn init_non_send_resource<R: 'static + FromWorld>(&mut self) -> &mut Self {
-        self.world.init_non_send_resource::<R>();
+        self.world_mut().init_non_send_resource::<R>();
         self
     }
 
+    /// Returns the type names of all resources with a Rust type currently in the [`World`], for
+    /// debugging.
+    ///
+    /// This reflects the live state: resources that were removed are not listed. Resources the
+    /// app inserts itself, such as [`Schedules`], are included.
+    pub fn resource_names(&self) -> Vec<&'static str> {
+        self.world()
+            .iter_resource_type_names()
+            .map(|(_, name)| name)
+            .collect()
+    }
+
     /// Sets the function that will be called when the app is run.
     ///
     /// The runner function `run_fn` is called only once by [`App::run`]. If the
     /// presence of a main loop in the app is desired, it is the responsibility of the runner
     /// function to provide it.
     ///
+    /// The runner may return the [`AppExit`] it finished with, which [`App::run_return`] hands
+    /// back to the caller. Runners returning `()` are still accepted and are treated as
+    /// returning [`AppExit::default`]. See [`RunnerOutput`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -435,8 +908,43 @@ impl App {
     /// App::new()
     ///     .set_runner(my_runner);
     /// ```
-    pub fn set_runner(&mut self, run_fn: impl FnOnce(App) + 'static + Send) -> &mut Self {
-        self.runner = Box::new(run_fn);
+    pub fn set_runner<R: RunnerOutput>(
+        &mut self,
+        run_fn: impl FnOnce(App) -> R + 'static + Send,
+    ) -> &mut Self {
+        self.runner = Box::new(move |app| run_fn(app).into_app_exit());
+        self
+    }
+
+    /// Sets callbacks that are called right before and right after every run of the
+    /// [main schedule](App::main_schedule_label) by [`App::update`], replacing any callbacks set
+    /// earlier.
+    ///
+    /// Both run on the thread calling [`App::update`], which is the main thread for the built-in
+    /// runners, and surround the whole main schedule rather than any single schedule in it. This
+    /// makes them suitable for frame markers of external profilers or frame pacing.
+    ///
+    /// No callbacks are set by default, and an [`App`] without them pays nothing for this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ens_app::prelude::*;
+    /// #
+    /// App::new().set_frame_callbacks(
+    ///     || println!("frame begin"),
+    ///     || println!("frame end"),
+    /// );
+    /// ```
+    pub fn set_frame_callbacks(
+        &mut self,
+        begin: impl FnMut() + Send + 'static,
+        end: impl FnMut() + Send + 'static,
+    ) -> &mut Self {
+        self.frame_callbacks = Some(FrameCallbacks {
+            begin: Box::new(begin),
+            end: Box::new(end),
+        });
         self
     }
 
@@ -453,6 +961,9 @@ impl App {
             })?;
         }
 
+        self.plugin_build_order
+            .push(PluginBuildRecord::new(plugin.name(), true));
+
         // Reserve that position in the plugin registry. if a plugin adds plugins, they will be correctly ordered
         let plugin_position_in_registry = self.plugin_registry.len();
         self.plugin_registry.push(Box::new(PlaceholderPlugin));
@@ -467,6 +978,42 @@ impl App {
         Ok(self)
     }
 
+    /// Records a [`Plugin`] of a [`PluginGroup`](super::PluginGroup) that was disabled and
+    /// therefore never built.
+    pub(crate) fn record_disabled_plugin(&mut self, plugin: &dyn Plugin) {
+        self.plugin_build_order
+            .push(PluginBuildRecord::new(plugin.name(), false));
+    }
+
+    /// Returns a record of every [`Plugin`] added to this [`App`], in the order they were built.
+    ///
+    /// Plugins disabled in a [`PluginGroup`](super::PluginGroup) are included at the position
+    /// they would have been built at.
+    pub fn plugin_build_order(&self) -> &[PluginBuildRecord] {
+        &self.plugin_build_order
+    }
+
+    /// Logs the [plugin build order](Self::plugin_build_order) as a single consolidated list.
+    ///
+    /// This is useful for auditing that two builds of an application add their plugins in the
+    /// same order. It is meant to be called once [`App::finish`] has run.
+    pub fn log_plugin_build_order(&self) {
+        let mut order = String::new();
+        for (index, record) in self.plugin_build_order.iter().enumerate() {
+            let state = if record.enabled {
+                "enabled"
+            } else {
+                "disabled"
+            };
+            order.push_str(&format!(
+                "\n  {index}: {} [{}] ({state})",
+                record.name, record.crate_name
+            ));
+        }
+
+        log::info!("plugin build order:{order}");
+    }
+
     /// Checks if a [`Plugin`] has already been added.
     ///
     /// This can be used by plugins to check if a plugin they depend upon has already been
@@ -507,6 +1054,36 @@ impl App {
             .collect()
     }
 
+    /// Removes the first added [`Plugin`] of type `T` and returns it.
+    ///
+    /// This only removes the plugin's registry entry, so that [`Plugin::finish`] and
+    /// [`Plugin::cleanup`] are not run for it and it no longer shows up in
+    /// [`get_added_plugins`](Self::get_added_plugins). Anything its [`Plugin::build`] already
+    /// added to the [`App`] is kept, and it stays in the
+    /// [plugin build order](Self::plugin_build_order).
+    ///
+    /// Returns `None` if no plugin of type `T` was added, if it is called from a
+    /// [`Plugin::build`], or if [`App::finish`] has already been called.
+    pub fn remove_plugin<T>(&mut self) -> Option<Box<dyn Plugin>>
+    where
+        T: Plugin,
+    {
+        // Plugins being built keep their registry slot by index, so the registry can't shift
+        if self.building_plugin_depth > 0
+            || matches!(
+                self.plugins_state,
+                PluginsState::Finished | PluginsState::Cleaned
+            )
+        {
+            return None;
+        }
+
+        let position = self.plugin_registry.iter().position(|p| p.is::<T>())?;
+        let plugin = self.plugin_registry.remove(position);
+        self.plugin_name_added.remove(plugin.name());
+        Some(plugin)
+    }
+
     /// Adds one or more [`Plugin`]s.
     ///
     /// One of Ens's core principles is modularity. All Ens features are implemented
@@ -563,7 +1140,7 @@ impl App {
     /// This method will overwrite any existing schedule with the same label.
     /// To avoid this behavior, use the `init_schedule` method instead.
     pub fn add_schedule(&mut self, schedule: Schedule) -> &mut Self {
-        let mut schedules = self.world.resource_mut::<Schedules>();
+        let mut schedules = self.world_mut().resource_mut::<Schedules>();
         schedules.insert(schedule);
 
         self
@@ -574,7 +1151,7 @@ impl App {
     /// See [`App::add_schedule`] to pass in a pre-constructed schedule.
     pub fn init_schedule(&mut self, label: impl ScheduleLabel) -> &mut Self {
         let label = label.intern();
-        let mut schedules = self.world.resource_mut::<Schedules>();
+        let mut schedules = self.world_mut().resource_mut::<Schedules>();
         if !schedules.contains(label) {
             schedules.insert(Schedule::new(label));
         }
@@ -583,13 +1160,25 @@ impl App {
 
     /// Gets read-only access to the [`Schedule`] with the provided `label` if it exists.
     pub fn get_schedule(&self, label: impl ScheduleLabel) -> Option<&Schedule> {
-        let schedules = self.world.get_resource::<Schedules>()?;
+        let schedules = self.world().get_resource::<Schedules>()?;
         schedules.get(label)
     }
 
+    /// Returns the name and sets of every system in the [`Schedule`] with the provided `label`,
+    /// in execution order.
+    ///
+    /// The list reflects the schedule as it was last built, so it is empty until the schedule
+    /// has run once, and systems added afterwards only show up after its next run. It is also
+    /// empty if the schedule does not exist. See [`Schedule::system_infos`].
+    pub fn systems_in_schedule(&self, label: impl ScheduleLabel) -> Vec<SystemInfo> {
+        self.get_schedule(label)
+            .and_then(|schedule| schedule.system_infos().ok())
+            .unwrap_or_default()
+    }
+
     /// Gets read-write access to a [`Schedule`] with the provided `label` if it exists.
     pub fn get_schedule_mut(&mut self, label: impl ScheduleLabel) -> Option<&mut Schedule> {
-        let schedules = self.world.get_resource_mut::<Schedules>()?;
+        let schedules = self.world_mut().get_resource_mut::<Schedules>()?;
         // We need to call .into_inner here to satisfy the borrow checker:
         // it can reason about reborrows using ordinary references but not the `Mut` smart pointer.
         schedules.into_inner().get_mut(label)
@@ -604,7 +1193,7 @@ impl App {
         f: impl FnOnce(&mut Schedule),
     ) -> &mut Self {
         let label = label.intern();
-        let mut schedules = self.world.resource_mut::<Schedules>();
+        let mut schedules = self.world_mut().resource_mut::<Schedules>();
 
         if schedules.get(label).is_none() {
             schedules.insert(Schedule::new(label));
@@ -617,17 +1206,67 @@ impl App {
         self
     }
 
+    /// Runs the schedules with the given `labels` right after the `after` schedule on every
+    /// update, in the given order.
+    ///
+    /// This is equivalent to chaining [`MainScheduleOrder::insert_after`] for each label, which is
+    /// handy for plugins that split their work into several consecutive phases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `after` is not part of [`MainScheduleOrder`].
+    ///
+    /// ```
+    /// # use ens::schedule::ScheduleLabel;
+    /// # use ens_app::prelude::*;
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct Gather;
+    /// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct Resolve;
+    ///
+    /// App::new().add_schedule_sequence(Update, &[Gather.intern(), Resolve.intern()]);
+    /// ```
+    pub fn add_schedule_sequence(
+        &mut self,
+        after: impl ScheduleLabel,
+        labels: &[InternedScheduleLabel],
+    ) -> &mut Self {
+        self.world_mut()
+            .resource_mut::<MainScheduleOrder>()
+            .insert_sequence_after(after, labels);
+        self
+    }
+
     /// Applies the provided [`ScheduleBuildSettings`] to all schedules.
     pub fn configure_schedules(
         &mut self,
         schedule_build_settings: ScheduleBuildSettings,
     ) -> &mut Self {
-        self.world
+        self.world_mut()
             .resource_mut::<Schedules>()
             .configure_schedules(schedule_build_settings);
         self
     }
 
+    /// Logs a warning whenever a schedule is built with the same system added more than once,
+    /// listing the duplicated systems.
+    ///
+    /// A system added twice by mistake silently runs twice per frame. This turns on
+    /// [`duplicate_system_detection`](ScheduleBuildSettings::duplicate_system_detection) for
+    /// every schedule that exists when this is called, keeping their other settings, so call it
+    /// once all systems have been added. It is off by default because generic systems are
+    /// sometimes added several times on purpose.
+    ///
+    /// This only applies to the main world.
+    pub fn warn_on_duplicate_systems(&mut self) -> &mut Self {
+        for (_, schedule) in self.world_mut().resource_mut::<Schedules>().iter_mut() {
+            let mut settings = schedule.get_build_settings();
+            settings.duplicate_system_detection = LogLevel::Warn;
+            schedule.set_build_settings(settings);
+        }
+        self
+    }
+
     /// When doing [ambiguity checking](ScheduleBuildSettings) this
     /// ignores systems that are ambiguous on [`Component`] T.
     ///
@@ -662,7 +1301,7 @@ impl App {
     /// app.update();
     /// ```
     pub fn allow_ambiguous_component<T: Component>(&mut self) -> &mut Self {
-        self.world.allow_ambiguous_component::<T>();
+        self.world_mut().allow_ambiguous_component::<T>();
         self
     }
 
@@ -701,7 +1340,7 @@ impl App {
     /// app.update();
     /// ```
     pub fn allow_ambiguous_resource<T: Resource>(&mut self) -> &mut Self {
-        self.world.allow_ambiguous_resource::<T>();
+        self.world_mut().allow_ambiguous_resource::<T>();
         self
     }
 
@@ -723,7 +1362,7 @@ impl App {
         S2: IntoSystemSet<M2>,
     {
         let schedule = schedule.intern();
-        let mut schedules = self.world.resource_mut::<Schedules>();
+        let mut schedules = self.world_mut().resource_mut::<Schedules>();
 
         if let Some(schedule) = schedules.get_mut(schedule) {
             let schedule: &mut Schedule = schedule;
@@ -738,7 +1377,7 @@ impl App {
     }
 }
 
-fn run_once(mut app: App) {
+fn run_once(mut app: App) -> AppExit {
     while app.plugins_state() == PluginsState::Adding {
         ens_tasks::tick_global_task_pools_on_main_thread();
     }
@@ -746,6 +1385,41 @@ fn run_once(mut app: App) {
     app.cleanup();
 
     app.update();
+
+    #[cfg(feature = "events")]
+    if let Some(app_exit_events) = app.world().get_resource::<Events<AppExit>>() {
+        if let Some(&exit) = ManualEventReader::<AppExit>::default()
+            .read(app_exit_events)
+            .last()
+        {
+            app.shutdown();
+            return exit;
+        }
+    }
+
+    AppExit::Success
+}
+
+/// A value a [runner function](App::set_runner) can return, converted into the [`AppExit`]
+/// returned by [`App::run_return`].
+///
+/// This is implemented for [`AppExit`] and for `()`, so runners written before runners could
+/// return a value keep working unchanged.
+pub trait RunnerOutput {
+    /// Converts the runner's return value into an [`AppExit`].
+    fn into_app_exit(self) -> AppExit;
+}
+
+impl RunnerOutput for AppExit {
+    fn into_app_exit(self) -> AppExit {
+        self
+    }
+}
+
+impl RunnerOutput for () {
+    fn into_app_exit(self) -> AppExit {
+        AppExit::Success
+    }
 }
 
 /// An event that indicates the [`App`] should exit. This will fully exit the app process at the
@@ -758,20 +1432,119 @@ fn run_once(mut app: App) {
 /// If you don't require access to other components or resources, consider implementing the [`Drop`]
 /// trait on components/resources for code that runs on exit. That saves you from worrying about
 /// system schedule ordering, and is idiomatic Rust.
+///
+/// The variant tells whether the app finished successfully, and is returned by
+/// [`App::run_return`]. It can also be returned from `main`, making the process exit with the
+/// matching status code, which lets supervisors such as systemd apply their restart policy.
+///
+/// Without the `events` feature, this is only the value returned by [`App::run_return`].
+#[cfg_attr(feature = "events", derive(Event))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AppExit {
+    /// The app exited without any problems.
+    #[default]
+    Success,
+    /// The app exited because of an error, with the given non-zero exit code.
+    Error(NonZeroU8),
+}
+
+impl AppExit {
+    /// Creates an [`AppExit::Error`] with the generic error code 1.
+    #[must_use]
+    pub const fn error() -> Self {
+        Self::Error(NonZeroU8::MIN)
+    }
+
+    /// Creates an [`AppExit`] from a process exit code: [`AppExit::Success`] for 0 and
+    /// [`AppExit::Error`] for anything else.
+    #[must_use]
+    pub const fn from_code(code: u8) -> Self {
+        match NonZeroU8::new(code) {
+            Some(code) => Self::Error(code),
+            None => Self::Success,
+        }
+    }
+
+    /// The process exit code for this [`AppExit`], 0 for [`AppExit::Success`].
+    #[must_use]
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::Error(code) => code.get(),
+        }
+    }
+
+    /// Returns `true` if this is [`AppExit::Success`].
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+
+    /// Returns `true` if this is [`AppExit::Error`].
+    #[must_use]
+    pub const fn is_error(&self) -> bool {
+        matches!(self, Self::Error(_))
+    }
+}
+
+impl Termination for AppExit {
+    fn report(self) -> ExitCode {
+        ExitCode::from(self.code())
+    }
+}
+
+/// An event sent by [`App::shutdown`] once the app stops after an [`AppExit`].
+///
+/// Systems in the [`Shutdown`] schedule see it, so they can release resources in an orderly
+/// fashion. Unlike [`AppExit`], it is sent by the [`App`] itself once the runner is about to
+/// return.
 #[cfg(feature = "events")]
-#[derive(Event, Debug, Clone, Default)]
-pub struct AppExit;
+#[derive(Event, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AppShutdown;
+
+/// The moment the [`App`] was started, inserted by [`App::run`] right before the runner is called.
+///
+/// This gives a single authoritative process uptime, e.g. for logs or status endpoints. It
+/// differs from the elapsed time of `Time<Real>`, which starts counting when the `TimePlugin` is
+/// built and is only advanced once per frame, so it lags behind during long frames and excludes
+/// the time spent building plugins.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Uptime {
+    started: Instant,
+}
+
+impl Uptime {
+    /// Creates an [`Uptime`] starting at the current instant.
+    pub fn now() -> Self {
+        Self {
+            started: Instant::now(),
+        }
+    }
+
+    /// The instant the app was started.
+    pub fn started(&self) -> Instant {
+        self.started
+    }
+
+    /// How long the app has been running.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use std::marker::PhantomData;
+    use std::{
+        marker::PhantomData,
+        time::{Duration, Instant},
+    };
 
     use ens::{
         schedule::{OnEnter, States},
         system::Commands,
     };
 
-    use crate::{App, Plugin};
+    use crate::{App, Plugin, Uptime};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -814,6 +1587,135 @@ mod tests {
         App::new().add_plugins((PluginD, PluginD));
     }
 
+    #[cfg(feature = "events")]
+    #[test]
+    fn shutdown_runs_once_app_exits() {
+        use crate::{AppExit, AppShutdown, Shutdown, Update};
+        use ens::{
+            access::ResMut,
+            event::{EventReader, EventWriter},
+            system::Resource,
+        };
+
+        #[derive(Resource, Default)]
+        struct Log(Vec<&'static str>);
+
+        fn record_shutdown(mut events: EventReader<AppShutdown>, mut log: ResMut<Log>) {
+            for _ in events.read() {
+                log.0.push("shutdown");
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Log>()
+            .add_systems(Update, record_shutdown)
+            .add_systems(Shutdown, record_shutdown)
+            .add_systems(
+                Update,
+                |mut log: ResMut<Log>, mut exit: EventWriter<AppExit>| {
+                    log.0.push("update");
+                    if log.0.len() == 2 {
+                        exit.send(AppExit::Success);
+                    }
+                },
+            );
+
+        // Cleaning up the plugins on the first frame doesn't shut the app down
+        app.run_until_exit();
+        assert_eq!(
+            app.world().resource::<Log>().0,
+            ["update", "update", "shutdown"]
+        );
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn shutdown_runs_in_reverse_build_order() {
+        use crate::{AppExit, Update};
+        use ens::{event::EventWriter, system::Resource};
+
+        #[derive(Resource, Default)]
+        struct Log(Vec<&'static str>);
+
+        fn record(app: &mut App, entry: &'static str) {
+            app.world_mut().resource_mut::<Log>().0.push(entry);
+        }
+
+        struct Network;
+        impl Plugin for Network {
+            fn build(&self, app: &mut App) {
+                record(app, "build network");
+            }
+            fn shutdown(&self, app: &mut App) {
+                record(app, "shutdown network");
+            }
+        }
+
+        struct Tasks;
+        impl Plugin for Tasks {
+            fn build(&self, app: &mut App) {
+                record(app, "build tasks");
+            }
+            fn shutdown(&self, app: &mut App) {
+                record(app, "shutdown tasks");
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Log>()
+            .add_plugins((Tasks, Network))
+            .add_systems(Update, |mut exit: EventWriter<AppExit>| {
+                exit.send(AppExit::Success);
+            });
+
+        app.run_until_exit();
+        assert_eq!(
+            app.world().resource::<Log>().0,
+            [
+                "build tasks",
+                "build network",
+                "shutdown network",
+                "shutdown tasks"
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_plugin_before_finish() {
+        let mut app = App::new();
+        app.add_plugins((PluginA, PluginB));
+
+        assert!(app.remove_plugin::<PluginA>().is_some());
+        assert!(app.get_added_plugins::<PluginA>().is_empty());
+        assert!(!app.is_plugin_added::<PluginA>());
+        assert!(app.remove_plugin::<PluginA>().is_none());
+
+        // The name is released, so the plugin can be added again.
+        app.add_plugins(PluginA);
+        assert_eq!(app.get_added_plugins::<PluginA>().len(), 1);
+
+        app.finish();
+        assert!(app.remove_plugin::<PluginB>().is_none());
+        assert!(app.is_plugin_added::<PluginB>());
+    }
+
+    #[test]
+    fn remove_plugin_from_plugin_build() {
+        struct RemovingPlugin;
+        impl Plugin for RemovingPlugin {
+            fn build(&self, app: &mut App) {
+                assert!(app.remove_plugin::<PluginA>().is_none());
+                app.add_plugins(PluginB);
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((PluginA, RemovingPlugin));
+        assert_eq!(app.get_added_plugins::<PluginA>().len(), 1);
+        assert_eq!(app.get_added_plugins::<RemovingPlugin>().len(), 1);
+        assert_eq!(app.get_added_plugins::<PluginB>().len(), 1);
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
@@ -863,6 +1765,548 @@ mod tests {
         assert_eq!(app.world.entities().len(), 2);
     }
 
+    #[test]
+    fn for_each_world_visits_main_world() {
+        let mut app = App::new();
+        let mut visited = 0;
+        app.for_each_world(|world| {
+            world.spawn_empty();
+            visited += 1;
+        });
+
+        assert_eq!(visited, 1);
+        assert_eq!(app.world.entities().len(), 1);
+    }
+
+    #[cfg(feature = "sub_app")]
+    #[test]
+    fn sub_app_extracts_and_runs_after_main() {
+        use crate::{AppLabel, SubApp, Update};
+        use ens::{access::ResMut, schedule::ScheduleLabel, system::Resource};
+        use ens_utils::label::DynEq;
+
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        struct RenderApp;
+        impl AppLabel for RenderApp {
+            fn dyn_clone(&self) -> Box<dyn AppLabel> {
+                Box::new(self.clone())
+            }
+            fn as_dyn_eq(&self) -> &dyn DynEq {
+                self
+            }
+            fn dyn_hash(&self, mut state: &mut dyn std::hash::Hasher) {
+                std::hash::Hash::hash(&std::any::TypeId::of::<Self>(), &mut state);
+            }
+        }
+
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Render;
+
+        #[derive(Resource, Default, Clone, Copy)]
+        struct Counter(u32);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut sub_app = SubApp::new(Render, |main, render| {
+            let counter = *main.resource::<Counter>();
+            render.insert_resource(counter);
+        });
+        sub_app.add_systems(increment);
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, increment)
+            .insert_sub_app(RenderApp, sub_app);
+
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Counter>().0, 2);
+        let render_world = &app.get_sub_app(RenderApp).unwrap().world;
+        assert_eq!(render_world.resource::<Counter>().0, 3);
+
+        let mut visited = 0;
+        app.for_each_world(|_| visited += 1);
+        assert_eq!(visited, 2);
+
+        assert!(app.remove_sub_app(RenderApp).is_some());
+        assert!(app.get_sub_app(RenderApp).is_none());
+    }
+
+    #[test]
+    fn plugin_build_order_is_recorded() {
+        use crate::PluginGroupBuilder;
+
+        let mut app = App::empty();
+        app.add_plugins((
+            PluginA,
+            PluginGroupBuilder::start::<crate::NoopPluginGroup>()
+                .add(PluginB)
+                .add(PluginD)
+                .disable::<PluginB>(),
+        ));
+
+        let order = app.plugin_build_order();
+        assert_eq!(order.len(), 3);
+        assert_eq!(order[0].name, std::any::type_name::<PluginA>());
+        assert_eq!(order[0].crate_name, "ens_app");
+        assert!(order[0].enabled);
+        assert_eq!(order[1].name, std::any::type_name::<PluginB>());
+        assert!(!order[1].enabled);
+        assert_eq!(order[2].name, std::any::type_name::<PluginD>());
+        assert!(order[2].enabled);
+    }
+
+    #[test]
+    fn systems_in_schedule_reflects_built_schedule() {
+        use crate::Update;
+
+        fn counter() {}
+
+        let mut app = App::new();
+        app.add_systems(Update, counter);
+        assert!(app.systems_in_schedule(Update).is_empty());
+
+        app.update();
+        let systems = app.systems_in_schedule(Update);
+        assert_eq!(systems.len(), 1);
+        assert!(systems[0].name.ends_with("counter"));
+    }
+
+    #[test]
+    fn add_systems_to_new_configures_only_new_schedules() {
+        use ens::schedule::{ExecutorKind, ScheduleLabel};
+
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Custom;
+
+        fn a() {}
+        fn b() {}
+
+        let mut app = App::new();
+        app.add_systems_to_new(
+            Custom,
+            |schedule| {
+                schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+            },
+            a,
+        );
+        app.add_systems_to_new(Custom, |_| panic!("schedule already exists"), b);
+
+        let schedule = app.get_schedule(Custom).unwrap();
+        assert_eq!(schedule.get_executor_kind(), ExecutorKind::SingleThreaded);
+        assert_eq!(schedule.systems_len(), 2);
+    }
+
+    #[cfg(all(feature = "events", feature = "change_detection"))]
+    #[test]
+    fn emit_change_events_reports_old_and_new_values() {
+        use crate::ResourceChanged;
+        use ens::{event::Events, system::Resource};
+
+        #[derive(Resource, Clone, PartialEq, Debug)]
+        struct Score(u32);
+
+        let mut app = App::new();
+        app.insert_resource(Score(0)).emit_change_events::<Score>();
+        app.update();
+
+        let read_changes = |app: &mut App| {
+            app.world
+                .resource_mut::<Events<ResourceChanged<Score>>>()
+                .drain()
+                .map(|change| (change.old.0, change.new.0))
+                .collect::<Vec<_>>()
+        };
+        assert!(read_changes(&mut app).is_empty());
+
+        app.world.resource_mut::<Score>().0 = 5;
+        app.update();
+        assert_eq!(read_changes(&mut app), [(0, 5)]);
+
+        // Mutated, but equal to the previous value.
+        app.world.resource_mut::<Score>().0 = 5;
+        app.update();
+        assert!(read_changes(&mut app).is_empty());
+
+        app.world.resource_mut::<Score>().0 = 7;
+        app.update();
+        assert_eq!(read_changes(&mut app), [(5, 7)]);
+    }
+
+    #[cfg(feature = "schedule_stats")]
+    #[test]
+    fn schedule_run_stats_count_main_schedules() {
+        use crate::{PostUpdate, ScheduleRunStats, Update};
+
+        fn slow() {
+            std::thread::sleep(Duration::from_millis(2));
+        }
+
+        let mut app = App::new();
+        app.add_systems(Update, slow);
+        app.update();
+        app.update();
+
+        let stats = app.world.resource::<ScheduleRunStats>();
+        assert_eq!(stats.run_count(Update), 2);
+        assert!(stats.last_duration(Update).unwrap() >= Duration::from_millis(2));
+        // No systems were added to `PostUpdate`, so the schedule doesn't exist.
+        assert_eq!(stats.run_count(PostUpdate), 0);
+        assert_eq!(stats.last_duration(PostUpdate), None);
+    }
+
+    #[test]
+    fn add_schedule_sequence_keeps_order_after_anchor() {
+        use crate::{MainScheduleOrder, PostUpdate, Update};
+        use ens::schedule::ScheduleLabel;
+
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct PhaseA;
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct PhaseB;
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct PhaseC;
+
+        let mut app = App::new();
+        app.add_schedule_sequence(Update, &[PhaseA.intern(), PhaseB.intern(), PhaseC.intern()]);
+
+        let labels = &app.world.resource::<MainScheduleOrder>().labels;
+        let update = labels
+            .iter()
+            .position(|label| *label == Update.intern())
+            .unwrap();
+        assert_eq!(
+            labels[update..update + 5],
+            [
+                Update.intern(),
+                PhaseA.intern(),
+                PhaseB.intern(),
+                PhaseC.intern(),
+                PostUpdate.intern()
+            ]
+        );
+    }
+
+    #[test]
+    fn warn_on_duplicate_systems_enables_detection() {
+        use crate::Update;
+        use ens::schedule::LogLevel;
+
+        fn twice() {}
+
+        let mut app = App::new();
+        app.add_systems(Update, (twice, twice));
+        assert_eq!(
+            app.get_schedule(Update)
+                .unwrap()
+                .get_build_settings()
+                .duplicate_system_detection,
+            LogLevel::Ignore
+        );
+
+        app.warn_on_duplicate_systems();
+        let settings = app.get_schedule(Update).unwrap().get_build_settings();
+        assert_eq!(settings.duplicate_system_detection, LogLevel::Warn);
+        assert!(settings.auto_insert_apply_deferred);
+        app.update();
+    }
+
+    #[test]
+    fn main_schedule_plugin_with_executor() {
+        use crate::{Main, MainSchedulePlugin, Update};
+        use ens::{access::ResMut, schedule::ExecutorKind, system::Resource};
+
+        #[derive(Resource, Default)]
+        struct Runs(u32);
+
+        let mut app = App::empty();
+        app.add_plugins(MainSchedulePlugin::with_executor(
+            ExecutorKind::MultiThreaded,
+        ))
+        .init_resource::<Runs>()
+        .add_systems(Update, |mut runs: ResMut<Runs>| runs.0 += 1);
+        assert_eq!(
+            app.get_schedule(Main).unwrap().get_executor_kind(),
+            ExecutorKind::MultiThreaded
+        );
+
+        app.update();
+        app.update();
+        assert_eq!(app.world.resource::<Runs>().0, 2);
+        assert_eq!(
+            App::new().get_schedule(Main).unwrap().get_executor_kind(),
+            ExecutorKind::SingleThreaded
+        );
+    }
+
+    #[test]
+    fn frame_callbacks_surround_main_schedule() {
+        use crate::Update;
+        use std::sync::{Arc, Mutex};
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut app = App::new();
+        let system_log = log.clone();
+        app.add_systems(Update, move || system_log.lock().unwrap().push("update"));
+        let (begin_log, end_log) = (log.clone(), log.clone());
+        app.set_frame_callbacks(
+            move || begin_log.lock().unwrap().push("begin"),
+            move || end_log.lock().unwrap().push("end"),
+        );
+
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["begin", "update", "end", "begin", "update", "end"]
+        );
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn run_return_propagates_app_exit_from_runner() {
+        use crate::{AppExit, ScheduleRunnerPlugin, Update};
+        use ens::event::EventWriter;
+
+        fn request_exit(mut exit: EventWriter<AppExit>) {
+            exit.send(AppExit::from_code(3));
+        }
+
+        let mut app = App::new();
+        app.add_plugins(ScheduleRunnerPlugin::run_loop())
+            .add_systems(Update, request_exit);
+        let exit = app.run_return();
+        assert!(exit.is_error());
+        assert_eq!(exit.code(), 3);
+
+        // Runners returning nothing are still accepted.
+        let mut app = App::new();
+        app.set_runner(|mut app: App| app.update());
+        assert!(app.run_return().is_success());
+    }
+
+    #[cfg(feature = "change_detection")]
+    #[test]
+    fn deferred_tracker_clearing_accumulates_changes() {
+        use crate::Update;
+        use ens::{access::ResMut, system::Resource};
+
+        #[derive(Resource, Default)]
+        struct Steps(u32);
+
+        fn step(mut steps: ResMut<Steps>) {
+            if steps.0 == 0 {
+                steps.0 = 1;
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Steps>().add_systems(Update, step);
+        app.clear_trackers();
+
+        app.update_without_clearing_trackers();
+        // The second sub-step doesn't touch the resource, but the first one's change is kept.
+        app.update_without_clearing_trackers();
+        assert!(app.world.is_resource_changed::<Steps>());
+
+        app.clear_trackers();
+        assert!(!app.world.is_resource_changed::<Steps>());
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn deferred_exit_runs_save_system_on_exit_frame() {
+        use crate::{AppExit, ScheduleRunnerPlugin, Update};
+        use ens::{
+            event::{EventReader, EventWriter},
+            schedule::IntoSystemConfigs,
+        };
+        use std::sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        };
+
+        fn request_exit(mut exit: EventWriter<AppExit>) {
+            exit.send(AppExit::Success);
+        }
+
+        let run = |defer_exit: bool| {
+            let saved = Arc::new(AtomicBool::new(false));
+            let save_flag = saved.clone();
+            let save = move |mut exits: EventReader<AppExit>| {
+                if exits.read().next().is_some() {
+                    save_flag.store(true, Ordering::SeqCst);
+                }
+            };
+
+            let mut app = App::new();
+            app.add_plugins(ScheduleRunnerPlugin {
+                defer_exit,
+                ..ScheduleRunnerPlugin::run_loop()
+            })
+            .add_systems(Update, (save, request_exit).chain());
+            app.run();
+
+            saved.load(Ordering::SeqCst)
+        };
+
+        assert!(!run(false));
+        assert!(run(true));
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn run_until_exit_stops_on_app_exit() {
+        use crate::{AppExit, Update};
+        use ens::{access::ResMut, event::EventWriter, system::Resource};
+
+        #[derive(Resource, Default)]
+        struct Frames(u32);
+
+        fn exit_every_three_frames(mut frames: ResMut<Frames>, mut exit: EventWriter<AppExit>) {
+            frames.0 += 1;
+            if frames.0 % 3 == 0 {
+                exit.send(AppExit::Success);
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Frames>()
+            .add_systems(Update, exit_every_three_frames);
+        app.run_until_exit();
+        assert_eq!(app.world.resource::<Frames>().0, 3);
+
+        // The previous exit is still buffered, but only a new one stops the loop
+        app.run_until_exit();
+        assert_eq!(app.world.resource::<Frames>().0, 6);
+    }
+
+    #[cfg(feature = "startup")]
+    #[test]
+    fn startup_systems_run_once() {
+        use crate::Startup;
+        use ens::{access::ResMut, system::Resource};
+
+        #[derive(Resource, Default)]
+        struct Runs(u32);
+
+        fn count(mut runs: ResMut<Runs>) {
+            runs.0 += 1;
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Runs>().add_systems(Startup, count);
+
+        app.update();
+        app.update();
+        app.update();
+        assert_eq!(app.world.resource::<Runs>().0, 1);
+
+        // Startup has already run, so systems added to it now never run.
+        app.add_systems(Startup, count);
+        app.update();
+        assert_eq!(app.world.resource::<Runs>().0, 1);
+    }
+
+    #[cfg(feature = "startup")]
+    #[test]
+    fn startup_stages_report_progress_in_order() {
+        use crate::StartupProgress;
+        use ens::{access::ResMut, system::Resource};
+
+        #[derive(Resource, Default)]
+        struct Log(Vec<String>);
+
+        fn stage(name: &'static str) -> impl FnMut(ResMut<Log>) {
+            move |mut log: ResMut<Log>| log.0.push(name.to_string())
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Log>()
+            .add_startup_stage_systems(5, stage("five"))
+            .add_startup_stage_systems(0, stage("zero"))
+            .add_startup_stage_systems(2, stage("two"))
+            .on_startup_progress(|world, progress| {
+                let entry = format!("{}/{}", progress.completed, progress.total);
+                world.resource_mut::<Log>().0.push(entry);
+            });
+
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<Log>().0,
+            ["zero", "1/3", "two", "2/3", "five", "3/3"]
+        );
+        assert_eq!(
+            *app.world.resource::<StartupProgress>(),
+            StartupProgress {
+                completed: 3,
+                total: 3
+            }
+        );
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn add_event_capped_drops_flooded_events() {
+        use crate::Update;
+        use ens::event::{Event, EventWriter, Events};
+
+        #[derive(Event)]
+        struct Packet;
+
+        let mut app = App::new();
+        app.add_event_capped::<Packet>(4).add_systems(
+            Update,
+            |mut packets: EventWriter<Packet>| {
+                packets.send_batch((0..10).map(|_| Packet));
+            },
+        );
+
+        app.update();
+        assert_eq!(app.world.resource::<Events<Packet>>().len(), 4);
+        assert_eq!(app.dropped_events::<Packet>(), 6);
+
+        app.update();
+        assert_eq!(app.dropped_events::<Packet>(), 12);
+    }
+
+    #[cfg(feature = "loop_wait")]
+    #[test]
+    fn wait_strategies_wait_at_least_the_duration() {
+        use crate::WaitStrategy;
+
+        let duration = Duration::from_millis(3);
+        for strategy in [
+            WaitStrategy::Sleep,
+            WaitStrategy::SpinSleep,
+            WaitStrategy::Yield,
+        ] {
+            let start = Instant::now();
+            strategy.wait(duration);
+            assert!(start.elapsed() >= duration, "{strategy:?} woke up early");
+        }
+    }
+
+    #[test]
+    fn uptime_is_inserted_before_runner() {
+        let mut app = App::new();
+        assert_eq!(app.uptime(), Duration::ZERO);
+
+        let before_run = Instant::now();
+        app.set_runner(move |app| {
+            let uptime = app.world.resource::<Uptime>();
+            assert!(uptime.started() >= before_run);
+            assert!(app.uptime() <= before_run.elapsed());
+        });
+        app.run();
+    }
+
     /// Custom runners should be in charge of when `app::update` gets called as they may need to
     /// coordinate some state.
     /// bug: <https://github.com/bevyengine/bevy/issues/10385>
@@ -894,4 +2338,23 @@ mod tests {
             .add_systems(PreUpdate, my_system)
             .run();
     }
+
+    #[test]
+    fn resource_names_reflect_live_resources() {
+        use ens::{schedule::Schedules, system::Resource};
+
+        #[derive(Resource)]
+        struct Score;
+
+        let mut app = App::new();
+        app.insert_resource(Score);
+        let score = std::any::type_name::<Score>();
+        assert!(app.resource_names().contains(&score));
+        assert!(app
+            .resource_names()
+            .contains(&std::any::type_name::<Schedules>()));
+
+        app.world.remove_resource::<Score>();
+        assert!(!app.resource_names().contains(&score));
+    }
 }
@@ -0,0 +1,42 @@
+use ens::{
+    event::{Event, EventWriter},
+    prelude::*,
+};
+
+/// Sent when the resource `R` changes to a value that differs from the previous one.
+///
+/// Enable it for a resource with [`App::emit_change_events`](crate::App::emit_change_events).
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ResourceChanged<R: Resource + Clone + PartialEq> {
+    /// The value of the resource before the change.
+    pub old: R,
+    /// The value of the resource after the change.
+    pub new: R,
+}
+
+/// Sends a [`ResourceChanged<R>`] event whenever `R` was changed since this system last ran and
+/// is no longer equal to the value seen back then.
+///
+/// The first time the resource is seen, its value is only recorded, since there is no old value
+/// to report.
+pub fn emit_resource_changed<R: Resource + Clone + PartialEq>(
+    resource: Option<Res<R>>,
+    mut previous: Local<Option<R>>,
+    mut events: EventWriter<ResourceChanged<R>>,
+) {
+    let Some(resource) = resource else {
+        return;
+    };
+
+    match previous.as_mut() {
+        None => *previous = Some(resource.clone()),
+        Some(old) if resource.is_changed() && *old != *resource => {
+            let old = std::mem::replace(old, resource.clone());
+            events.send(ResourceChanged {
+                old,
+                new: resource.clone(),
+            });
+        }
+        Some(_) => {}
+    }
+}
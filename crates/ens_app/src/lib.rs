@@ -2,19 +2,31 @@
 //! application.
 
 mod app;
+#[cfg(all(feature = "events", feature = "change_detection"))]
+mod change_events;
 mod main_schedule;
 mod plugin;
 mod plugin_group;
 mod schedule_runner;
+#[cfg(feature = "startup")]
+mod startup_stages;
+#[cfg(feature = "sub_app")]
+mod sub_app;
 
 #[cfg(feature = "multi-treaded")]
 mod task_pool_plugin;
 
 pub use app::*;
+#[cfg(all(feature = "events", feature = "change_detection"))]
+pub use change_events::*;
 pub use main_schedule::*;
 pub use plugin::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
+#[cfg(feature = "startup")]
+pub use startup_stages::*;
+#[cfg(feature = "sub_app")]
+pub use sub_app::*;
 
 #[cfg(feature = "multi-treaded")]
 pub use task_pool_plugin::*;
@@ -27,7 +39,7 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         app::App,
-        main_schedule::{Main, PostUpdate, PreUpdate, Update},
+        main_schedule::{FixedUpdate, Main, PostUpdate, PreUpdate, Update},
         Plugin, PluginGroup,
     };
 
@@ -35,7 +47,10 @@ pub mod prelude {
     pub use create::task_pool_plugin::TaskPoolPlugin;
 
     #[cfg(feature = "startup")]
-    pub use crate::main_schedule::{PostStartup, PreStartup, Startup};
+    pub use crate::{
+        main_schedule::{PostStartup, PreStartup, Startup},
+        StartupProgress, StartupStage,
+    };
 
     #[cfg(feature = "states")]
     pub use crate::main_schedule::StateTransition;
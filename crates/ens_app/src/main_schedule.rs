@@ -6,6 +6,11 @@ use ens::{
     world::World,
 };
 
+#[cfg(feature = "schedule_stats")]
+use ens_utils::HashMap;
+#[cfg(feature = "schedule_stats")]
+use std::time::{Duration, Instant};
+
 /// The schedule that contains the app logic that is evaluated each tick of [`App::update()`].
 ///
 /// By default, it will run the following schedules in the given order:
@@ -13,11 +18,14 @@ use ens::{
 /// On the first run of the schedule (and only on the first run), it will run:
 /// * [`PreStartup`]
 /// * [`Startup`]
+/// * [`RunStartupStages`]
 /// * [`PostStartup`]
 ///
 /// Then it will run:
 /// * [`PreUpdate`]
 /// * [`StateTransition`]
+/// * [`RunFixedUpdateLoop`]
+///     * This will run [`FixedUpdate`] zero to many times, based on how much time has elapsed.
 /// * [`Update`]
 /// * [`PostUpdate`]
 ///
@@ -33,11 +41,21 @@ pub struct PreStartup;
 
 /// The schedule that runs once when the app starts.
 ///
+/// It runs on the first update of the [`Main`] schedule. Systems added to it afterwards are
+/// harmless, but never run.
+///
 /// See the [`Main`] schedule for some details about how schedules are run.
 #[cfg(feature = "startup")]
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Startup;
 
+/// Runs the [`StartupStage`](crate::StartupStage) schedules in order, once, after [`Startup`].
+///
+/// See the [`Main`] schedule for some details about how schedules are run.
+#[cfg(feature = "startup")]
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RunStartupStages;
+
 /// The schedule that runs once after [`Startup`].
 ///
 /// See the [`Main`] schedule for some details about how schedules are run.
@@ -62,6 +80,26 @@ pub struct PreUpdate;
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct StateTransition;
 
+/// Runs the [`FixedUpdate`] schedule in a loop until all relevant elapsed time has been
+/// "consumed".
+///
+/// The loop itself is driven by a system added by the time plugin, so without it this schedule
+/// does nothing.
+///
+/// See the [`Main`] schedule for some details about how schedules are run.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RunFixedUpdateLoop;
+
+/// The schedule that contains app logic to be ran at a fixed timestep, independent of the frame
+/// rate. It may run zero, one or several times per frame.
+///
+/// Systems here should read the current time from the default clock, which is set to the fixed
+/// clock for the duration of this schedule.
+///
+/// See the [`Main`] schedule for some details about how schedules are run.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedUpdate;
+
 /// The schedule that contains app logic to be ran once per frame.
 ///
 /// See the [`Main`] schedule for some details about how schedules are run.
@@ -79,6 +117,15 @@ pub struct Update;
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PostUpdate;
 
+/// The schedule that runs once when the app stops after an [`AppExit`](crate::AppExit), run by
+/// [`App::shutdown`] outside of [`Main`].
+///
+/// Systems in it can release resources in an orderly fashion, and see the
+/// [`AppShutdown`](crate::AppShutdown) event sent right before.
+#[cfg(feature = "events")]
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Shutdown;
+
 /// Defines the schedules to be run for the [`Main`] schedule, including
 /// their order.
 #[derive(Resource, Debug)]
@@ -97,11 +144,17 @@ impl Default for MainScheduleOrder {
                 PreUpdate.intern(),
                 #[cfg(feature = "states")]
                 StateTransition.intern(),
+                RunFixedUpdateLoop.intern(),
                 Update.intern(),
                 PostUpdate.intern(),
             ],
             #[cfg(feature = "startup")]
-            startup_labels: vec![PreStartup.intern(), Startup.intern(), PostStartup.intern()],
+            startup_labels: vec![
+                PreStartup.intern(),
+                Startup.intern(),
+                RunStartupStages.intern(),
+                PostStartup.intern(),
+            ],
         }
     }
 }
@@ -109,14 +162,35 @@ impl Default for MainScheduleOrder {
 impl MainScheduleOrder {
     /// Adds the given `schedule` after the `after` schedule in the main list of schedules.
     pub fn insert_after(&mut self, after: impl ScheduleLabel, schedule: impl ScheduleLabel) {
-        let index = self
-            .labels
-            .iter()
-            .position(|current| (**current).eq(&after))
-            .unwrap_or_else(|| panic!("Expected {after:?} to exist"));
+        let index = position_of(&self.labels, &after);
         self.labels.insert(index + 1, schedule.intern());
     }
 
+    /// Adds the given `schedule` before the `before` schedule in the main list of schedules.
+    pub fn insert_before(&mut self, before: impl ScheduleLabel, schedule: impl ScheduleLabel) {
+        let index = position_of(&self.labels, &before);
+        self.labels.insert(index, schedule.intern());
+    }
+
+    /// Adds the given `schedules` after the `after` schedule in the main list of schedules, keeping
+    /// their relative order.
+    pub fn insert_sequence_after(
+        &mut self,
+        after: impl ScheduleLabel,
+        schedules: &[InternedScheduleLabel],
+    ) {
+        let index = position_of(&self.labels, &after);
+        self.labels
+            .splice(index + 1..index + 1, schedules.iter().copied());
+    }
+
+    /// Removes the given `schedule` from the main list of schedules, so that [`Main`] no longer
+    /// runs it. The schedule itself and its systems are kept.
+    pub fn remove(&mut self, schedule: impl ScheduleLabel) {
+        let index = position_of(&self.labels, &schedule);
+        self.labels.remove(index);
+    }
+
     /// Adds the given `schedule` after the `after` schedule in the list of startup schedules.
     #[cfg(feature = "startup")]
     pub fn insert_startup_after(
@@ -124,46 +198,199 @@ impl MainScheduleOrder {
         after: impl ScheduleLabel,
         schedule: impl ScheduleLabel,
     ) {
-        let index = self
-            .startup_labels
-            .iter()
-            .position(|current| (**current).eq(&after))
-            .unwrap_or_else(|| panic!("Expected {after:?} to exist"));
+        let index = position_of(&self.startup_labels, &after);
         self.startup_labels.insert(index + 1, schedule.intern());
     }
 }
 
+/// Finds the index of `label` in `labels`. Panics if it is not found.
+fn position_of(labels: &[InternedScheduleLabel], label: &impl ScheduleLabel) -> usize {
+    labels
+        .iter()
+        .position(|current| (**current).eq(label))
+        .unwrap_or_else(|| panic!("Expected {label:?} to exist"))
+}
+
 impl Main {
     /// A system that runs the "main schedule"
-    pub fn run_main(world: &mut World) {
-        //, mut run_at_least_once: Local<bool>) {
-        /*if !*run_at_least_once {
+    pub fn run_main(world: &mut World, mut run_at_least_once: Local<bool>) {
+        if !*run_at_least_once {
+            #[cfg(feature = "startup")]
             world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
                 for &label in &order.startup_labels {
                     let _ = world.try_run_schedule(label);
                 }
             });
             *run_at_least_once = true;
-        }*/
+        }
 
         world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
             for &label in &order.labels {
-                let _ = world.try_run_schedule(label);
+                #[cfg(feature = "schedule_stats")]
+                let start = Instant::now();
+
+                let _result = world.try_run_schedule(label);
+
+                #[cfg(feature = "schedule_stats")]
+                if _result.is_ok() {
+                    if let Some(mut stats) = world.get_resource_mut::<ScheduleRunStats>() {
+                        stats.record(label, start.elapsed());
+                    }
+                }
             }
         });
     }
 }
 
+/// Counts how many times each schedule of the [`Main`] schedule ran and how long its last run
+/// took.
+///
+/// This is much coarser than timing individual systems, but nearly free, and enough to tell which
+/// top-level phase dominates a frame. Only the schedules run by [`Main::run_main`], i.e. the ones
+/// listed in [`MainScheduleOrder::labels`], are tracked, and only once they exist.
+#[cfg(feature = "schedule_stats")]
+#[derive(Resource, Debug, Default)]
+pub struct ScheduleRunStats {
+    stats: HashMap<InternedScheduleLabel, ScheduleStats>,
+}
+
+#[cfg(feature = "schedule_stats")]
+#[derive(Debug, Default, Clone, Copy)]
+struct ScheduleStats {
+    run_count: u64,
+    last_duration: Duration,
+}
+
+#[cfg(feature = "schedule_stats")]
+impl ScheduleRunStats {
+    /// How long the last run of the schedule with the given `label` took, or [`None`] if it has
+    /// not run yet.
+    pub fn last_duration(&self, label: impl ScheduleLabel) -> Option<Duration> {
+        self.stats
+            .get(&label.intern())
+            .map(|stats| stats.last_duration)
+    }
+
+    /// How many times the schedule with the given `label` has run.
+    pub fn run_count(&self, label: impl ScheduleLabel) -> u64 {
+        self.stats
+            .get(&label.intern())
+            .map_or(0, |stats| stats.run_count)
+    }
+
+    /// Iterates over the label, run count and last duration of every schedule that has run.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, u64, Duration)> + '_ {
+        self.stats
+            .iter()
+            .map(|(label, stats)| (*label, stats.run_count, stats.last_duration))
+    }
+
+    fn record(&mut self, label: InternedScheduleLabel, duration: Duration) {
+        let stats = self.stats.entry(label).or_default();
+        stats.run_count += 1;
+        stats.last_duration = duration;
+    }
+}
+
 /// Initializes the [`Main`] schedule, sub schedules, and resources for a given [`App`].
+///
+/// [`Main`] uses the [`SingleThreaded`](ExecutorKind::SingleThreaded) executor. It is a
+/// "facilitator" schedule whose only job is running the other schedules one after another, so
+/// it has nothing to parallelize and a multi-threaded executor would only add overhead. Use
+/// [`MainSchedulePlugin::with_executor`] if [`Main`] holds parallelizable systems of its own.
 pub struct MainSchedulePlugin;
 
+impl MainSchedulePlugin {
+    /// Creates a [`MainScheduleExecutorPlugin`], which sets up the same schedules and resources
+    /// as this plugin but runs [`Main`] on the given executor.
+    ///
+    /// [`App::new`] already adds [`MainSchedulePlugin`], so start from [`App::empty`] to use this.
+    pub fn with_executor(executor_kind: ExecutorKind) -> MainScheduleExecutorPlugin {
+        MainScheduleExecutorPlugin { executor_kind }
+    }
+}
+
 impl Plugin for MainSchedulePlugin {
     fn build(&self, app: &mut App) {
-        // simple "facilitator" schedules benefit from simpler single threaded scheduling
-        let mut main_schedule = Schedule::new(Main);
-        main_schedule.set_executor_kind(ExecutorKind::SingleThreaded);
-        app.add_schedule(main_schedule)
-            .init_resource::<MainScheduleOrder>()
-            .add_systems(Main, Main::run_main);
+        build_main_schedule(app, ExecutorKind::SingleThreaded);
+    }
+}
+
+/// A [`MainSchedulePlugin`] running [`Main`] on a custom executor, created with
+/// [`MainSchedulePlugin::with_executor`].
+pub struct MainScheduleExecutorPlugin {
+    executor_kind: ExecutorKind,
+}
+
+impl Plugin for MainScheduleExecutorPlugin {
+    fn build(&self, app: &mut App) {
+        build_main_schedule(app, self.executor_kind);
+    }
+}
+
+fn build_main_schedule(app: &mut App, executor_kind: ExecutorKind) {
+    let mut main_schedule = Schedule::new(Main);
+    main_schedule.set_executor_kind(executor_kind);
+    app.add_schedule(main_schedule)
+        .init_resource::<MainScheduleOrder>()
+        .add_systems(Main, Main::run_main);
+
+    #[cfg(feature = "schedule_stats")]
+    app.init_resource::<ScheduleRunStats>();
+
+    #[cfg(feature = "startup")]
+    app.init_resource::<crate::StartupProgress>()
+        .init_resource::<crate::StartupStages>()
+        .add_systems(RunStartupStages, crate::run_startup_stages);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ens::schedule::ScheduleLabel;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Input;
+
+    #[test]
+    fn insert_before_update() {
+        let mut order = MainScheduleOrder::default();
+        order.insert_before(Update, Input);
+
+        assert_eq!(
+            order.labels,
+            vec![
+                PreUpdate.intern(),
+                #[cfg(feature = "states")]
+                StateTransition.intern(),
+                RunFixedUpdateLoop.intern(),
+                Input.intern(),
+                Update.intern(),
+                PostUpdate.intern(),
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_post_update() {
+        let mut order = MainScheduleOrder::default();
+        order.remove(PostUpdate);
+
+        assert_eq!(
+            order.labels,
+            vec![
+                PreUpdate.intern(),
+                #[cfg(feature = "states")]
+                StateTransition.intern(),
+                RunFixedUpdateLoop.intern(),
+                Update.intern(),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected Input to exist")]
+    fn remove_missing_panics() {
+        MainScheduleOrder::default().remove(Input);
     }
 }
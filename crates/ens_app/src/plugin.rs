@@ -1,7 +1,7 @@
 use downcast_rs::{impl_downcast, Downcast};
 
 use crate::App;
-use std::any::Any;
+use std::any::{Any, TypeId};
 
 /// A collection of app logic and configuration.
 ///
@@ -21,6 +21,8 @@ use std::any::Any;
 /// * once the app started, it will wait for all registered [`Plugin::ready`] to return `true`
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
+/// * once the app stops after an [`AppExit`](crate::AppExit), it calls all registered
+///   [`Plugin::shutdown`] in reverse order
 ///
 /// ## Defining a plugin.
 ///
@@ -79,6 +81,17 @@ pub trait Plugin: Downcast + Any + Send + Sync {
         // do nothing
     }
 
+    /// Releases what this plugin set up, once the app stops after an
+    /// [`AppExit`](crate::AppExit).
+    ///
+    /// [`App::shutdown`] calls this in the reverse of the order plugins were added in, so teardown
+    /// mirrors setup: a plugin is shut down before the plugins it was built after, e.g. the
+    /// network before the task pool it runs on. Requires the `events` feature, without which the
+    /// app can't tell that it is exiting.
+    fn shutdown(&self, _app: &mut App) {
+        // do nothing
+    }
+
     /// Configures a name for the [`Plugin`] which is primarily used for checking plugin
     /// uniqueness and debugging.
     fn name(&self) -> &str {
@@ -90,6 +103,16 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// The [`TypeId`]s of the plugins that must be built before this one.
+    ///
+    /// When both are in the same [`PluginGroup`](crate::PluginGroup), the group builds the
+    /// dependencies first, regardless of the order they were added in. Dependencies outside of
+    /// the group are not checked, and plugins added on their own are built in the order they are
+    /// added.
+    fn depends_on(&self) -> Vec<TypeId> {
+        Vec::new()
+    }
 }
 
 impl_downcast!(Plugin);
@@ -106,6 +129,19 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
 /// See `ens_dynamic_plugin/src/loader.rs#dynamically_load_plugin`.
 pub type CreatePlugin = unsafe fn() -> *mut dyn Plugin;
 
+/// The version of the interface between an app and its dynamically loaded plugins.
+///
+/// Deriving `DynamicPlugin` exports it from the plugin, so that a plugin built against an
+/// incompatible `ens_app` is rejected instead of being loaded. It is bumped whenever that
+/// interface changes.
+pub const DYNAMIC_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// A type representing an unsafe function that returns the [`DYNAMIC_PLUGIN_ABI_VERSION`] a
+/// dynamic plugin was built with.
+///
+/// See `ens_dynamic_plugin/src/loader.rs#dynamically_load_plugin`.
+pub type PluginAbiVersion = unsafe fn() -> u32;
+
 /// Types that represent a set of [`Plugin`]s.
 ///
 /// This is implemented for all types which implement [`Plugin`],
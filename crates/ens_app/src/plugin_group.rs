@@ -110,6 +110,42 @@ impl PluginGroupBuilder {
         self
     }
 
+    /// Replaces the [`Plugin`] of type `Old` with `plugin`, which takes its place in the build
+    /// order and keeps whether it was enabled.
+    ///
+    /// Unlike [`set`](Self::set), the replacement may be of a different type, e.g. to substitute
+    /// a custom implementation for a default plugin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no plugin of type `Old` in the group, or if a plugin of type `New` is
+    /// already in the group.
+    pub fn replace<Old: Plugin, New: Plugin>(mut self, plugin: New) -> Self {
+        let index = self.index_of::<Old>();
+        let old_id = TypeId::of::<Old>();
+        let new_id = TypeId::of::<New>();
+        if new_id != old_id && self.plugins.contains_key(&new_id) {
+            panic!(
+                "{} already exists in this PluginGroup",
+                std::any::type_name::<New>(),
+            );
+        }
+
+        let enabled = self
+            .plugins
+            .remove(&old_id)
+            .is_some_and(|entry| entry.enabled);
+        self.order[index] = new_id;
+        self.plugins.insert(
+            new_id,
+            PluginEntry {
+                plugin: Box::new(plugin),
+                enabled,
+            },
+        );
+        self
+    }
+
     /// Adds the plugin [`Plugin`] at the end of this [`PluginGroupBuilder`]. If the plugin was
     /// already in the group, it is removed from its previous place.
     // This is not confusing, clippy!
@@ -189,15 +225,65 @@ impl PluginGroupBuilder {
         self
     }
 
+    /// Returns the order in which to build the plugins: the specified order, except that every
+    /// plugin is moved after the plugins of the group it [depends on](Plugin::depends_on).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dependencies form a cycle.
+    fn dependency_order(&self) -> Vec<TypeId> {
+        fn visit(
+            builder: &PluginGroupBuilder,
+            ty: TypeId,
+            visiting: &mut Vec<TypeId>,
+            sorted: &mut Vec<TypeId>,
+        ) {
+            if sorted.contains(&ty) {
+                return;
+            }
+            if let Some(start) = visiting.iter().position(|&visited| visited == ty) {
+                let cycle: Vec<_> = visiting[start..]
+                    .iter()
+                    .chain([&ty])
+                    .map(|ty| builder.plugins[ty].plugin.name())
+                    .collect();
+                panic!(
+                    "Plugin dependency cycle in group {}: {}",
+                    builder.group_name,
+                    cycle.join(" -> ")
+                );
+            }
+
+            visiting.push(ty);
+            for dependency in builder.plugins[&ty].plugin.depends_on() {
+                if builder.plugins.contains_key(&dependency) {
+                    visit(builder, dependency, visiting, sorted);
+                }
+            }
+            visiting.pop();
+            sorted.push(ty);
+        }
+
+        let mut sorted = Vec::with_capacity(self.order.len());
+        for &ty in &self.order {
+            if self.plugins.contains_key(&ty) {
+                visit(self, ty, &mut Vec::new(), &mut sorted);
+            }
+        }
+        sorted
+    }
+
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
-    /// in the order specified.
+    /// in the order specified, except that plugins are built after the plugins of the group they
+    /// [depend on](Plugin::depends_on).
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugin in the group was already added to the application.
+    /// Panics if one of the plugin in the group was already added to the application, or if the
+    /// dependencies of the plugins form a cycle.
     #[track_caller]
     pub fn finish(mut self, app: &mut App) {
-        for ty in &self.order {
+        for ty in &self.dependency_order() {
             if let Some(entry) = self.plugins.remove(ty) {
                 if entry.enabled {
                     if let Err(AppError::DuplicatePlugin { plugin_name }) =
@@ -209,6 +295,8 @@ impl PluginGroupBuilder {
                             self.group_name
                         );
                     }
+                } else {
+                    app.record_disabled_plugin(entry.plugin.as_ref());
                 }
             }
         }
@@ -237,6 +325,7 @@ impl PluginGroup for NoopPluginGroup {
 mod tests {
     use super::PluginGroupBuilder;
     use crate::{App, NoopPluginGroup, Plugin};
+    use std::any::TypeId;
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -401,4 +490,125 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn dependencies_build_first() {
+        struct Base;
+        impl Plugin for Base {
+            fn build(&self, _: &mut App) {}
+        }
+
+        struct Left;
+        impl Plugin for Left {
+            fn build(&self, _: &mut App) {}
+            fn depends_on(&self) -> Vec<TypeId> {
+                vec![TypeId::of::<Base>()]
+            }
+        }
+
+        struct Right;
+        impl Plugin for Right {
+            fn build(&self, _: &mut App) {}
+            fn depends_on(&self) -> Vec<TypeId> {
+                vec![TypeId::of::<Base>()]
+            }
+        }
+
+        struct Top;
+        impl Plugin for Top {
+            fn build(&self, _: &mut App) {}
+            fn depends_on(&self) -> Vec<TypeId> {
+                vec![TypeId::of::<Left>(), TypeId::of::<Right>()]
+            }
+        }
+
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(Top)
+            .add(Right)
+            .add(PluginA)
+            .add(Left)
+            .add(Base);
+
+        assert_eq!(
+            group.dependency_order(),
+            vec![
+                TypeId::of::<Base>(),
+                TypeId::of::<Left>(),
+                TypeId::of::<Right>(),
+                TypeId::of::<Top>(),
+                TypeId::of::<PluginA>(),
+            ]
+        );
+
+        let mut app = App::empty();
+        group.finish(&mut app);
+        let order: Vec<_> = app
+            .plugin_build_order()
+            .iter()
+            .map(|record| record.name.rsplit("::").next().unwrap())
+            .collect();
+        assert_eq!(order, ["Base", "Left", "Right", "Top", "PluginA"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Plugin dependency cycle")]
+    fn dependency_cycle_panics() {
+        struct Chicken;
+        impl Plugin for Chicken {
+            fn build(&self, _: &mut App) {}
+            fn depends_on(&self) -> Vec<TypeId> {
+                vec![TypeId::of::<Egg>()]
+            }
+        }
+
+        struct Egg;
+        impl Plugin for Egg {
+            fn build(&self, _: &mut App) {}
+            fn depends_on(&self) -> Vec<TypeId> {
+                vec![TypeId::of::<Chicken>()]
+            }
+        }
+
+        PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(Chicken)
+            .add(Egg)
+            .finish(&mut App::new());
+    }
+
+    #[test]
+    fn replace_keeps_slot() {
+        struct CustomB(u32);
+        impl Plugin for CustomB {
+            fn build(&self, _: &mut App) {}
+        }
+
+        let group = PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .add(PluginB)
+            .add(PluginC)
+            .replace::<PluginB, CustomB>(CustomB(7));
+
+        assert_eq!(
+            group.order,
+            vec![
+                TypeId::of::<PluginA>(),
+                TypeId::of::<CustomB>(),
+                TypeId::of::<PluginC>(),
+            ]
+        );
+        assert!(!group.plugins.contains_key(&TypeId::of::<PluginB>()));
+
+        let mut app = App::empty();
+        group.finish(&mut app);
+        assert!(!app.is_plugin_added::<PluginB>());
+        assert_eq!(app.get_added_plugins::<CustomB>()[0].0, 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Plugin does not exist in group")]
+    fn replace_missing_panics() {
+        PluginGroupBuilder::start::<NoopPluginGroup>()
+            .add(PluginA)
+            .replace::<PluginB, PluginC>(PluginC);
+    }
 }
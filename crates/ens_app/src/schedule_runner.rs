@@ -1,7 +1,8 @@
-use crate::{app::App, plugin::Plugin, PluginsState};
-
-#[cfg(feature = "events")]
-use crate::app::AppExit;
+use crate::{
+    app::{App, AppExit},
+    plugin::Plugin,
+    PluginsState,
+};
 
 #[cfg(feature = "events")]
 use ens::event::{Events, ManualEventReader};
@@ -16,16 +17,115 @@ use std::time::{Duration, Instant};
 pub enum RunMode {
     /// Indicates that the [`App`]'s schedule should run repeatedly.
     Loop,
+    /// Indicates that the [`App`]'s schedule should run repeatedly, at most once every `wait`.
     #[cfg(feature = "loop_wait")]
     LoopWait {
         /// The minimum [`Duration`] to wait after a [`Schedule`](ens::schedule::Schedule)
         /// has completed before repeating. A value of [`None`] will not wait.
         wait: Duration,
+        /// How the rest of `wait` is spent once an update finished early.
+        strategy: WaitStrategy,
+    },
+    /// Indicates that the [`App`]'s schedule should run repeatedly, starting an update every
+    /// `target`.
+    ///
+    /// Unlike [`RunMode::LoopWait`], the wait is measured against a running deadline rather than
+    /// the end of the last update, so oversleeping in one frame is made up for in the next and the
+    /// average frame time converges on `target`. A frame that overruns its deadline is not caught
+    /// up on: the next update starts right away and the deadline is reset from there.
+    #[cfg(feature = "loop_wait")]
+    LoopRate {
+        /// The [`Duration`] between the starts of two consecutive updates.
+        target: Duration,
+        /// How the time until the next update is spent once an update finished early.
+        strategy: WaitStrategy,
     },
     /// Indicates that the [`App`]'s schedule should run only once.
     Once,
 }
 
+/// Computes how long [`RunMode::LoopRate`] waits after each update.
+#[cfg(feature = "loop_wait")]
+#[derive(Debug)]
+struct FramePacer {
+    target: Duration,
+    deadline: Instant,
+}
+
+#[cfg(feature = "loop_wait")]
+impl FramePacer {
+    /// Starts pacing frames with the first frame starting at `start`.
+    fn new(target: Duration, start: Instant) -> Self {
+        Self {
+            target,
+            deadline: start + target,
+        }
+    }
+
+    /// Returns how long to wait before starting the next frame, given that the current frame
+    /// ended at `now`, and advances the deadline.
+    fn frame_ended(&mut self, now: Instant) -> Option<Duration> {
+        if now < self.deadline {
+            let sleep = self.deadline - now;
+            self.deadline += self.target;
+            Some(sleep)
+        } else {
+            self.deadline = now + self.target;
+            None
+        }
+    }
+}
+
+/// How [`RunMode::LoopWait`] and [`RunMode::LoopRate`] wait out the rest of a frame.
+///
+/// The strategies trade CPU usage for precision. The default, [`WaitStrategy::Sleep`], uses no
+/// CPU while waiting but can oversleep by the OS scheduler granularity, which is often 1-15ms on
+/// Windows, making the frame rate unsteady.
+#[cfg(feature = "loop_wait")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WaitStrategy {
+    /// Sleeps the thread for the whole duration.
+    #[default]
+    Sleep,
+    /// Sleeps for all but the last [`WaitStrategy::SPIN_DURATION`], then spins until the deadline.
+    ///
+    /// Accurate to well below a millisecond while keeping a core busy for at most
+    /// [`WaitStrategy::SPIN_DURATION`] per frame.
+    SpinSleep,
+    /// Repeatedly calls [`std::thread::yield_now`] until the deadline.
+    ///
+    /// As accurate as spinning, but keeps a core busy for the whole duration unless other threads
+    /// are waiting to run.
+    Yield,
+}
+
+#[cfg(feature = "loop_wait")]
+impl WaitStrategy {
+    /// The part of the duration [`WaitStrategy::SpinSleep`] spins instead of sleeping.
+    pub const SPIN_DURATION: Duration = Duration::from_millis(1);
+
+    /// Blocks the current thread for at least `duration` using this strategy.
+    pub fn wait(self, duration: Duration) {
+        let deadline = Instant::now() + duration;
+        match self {
+            WaitStrategy::Sleep => std::thread::sleep(duration),
+            WaitStrategy::SpinSleep => {
+                if let Some(sleep) = duration.checked_sub(Self::SPIN_DURATION) {
+                    std::thread::sleep(sleep);
+                }
+                while Instant::now() < deadline {
+                    std::hint::spin_loop();
+                }
+            }
+            WaitStrategy::Yield => {
+                while Instant::now() < deadline {
+                    std::thread::yield_now();
+                }
+            }
+        }
+    }
+}
+
 impl Default for RunMode {
     fn default() -> Self {
         RunMode::Loop
@@ -47,6 +147,14 @@ impl Default for RunMode {
 pub struct ScheduleRunnerPlugin {
     /// Determines whether the [`Schedule`](ens::schedule::Schedule) is run once or repeatedly.
     pub run_mode: RunMode,
+    /// When `true`, the runner performs exactly one more [`App::update`] after it first sees an
+    /// [`AppExit`] event before exiting. Systems that react to [`AppExit`], such as saving state on
+    /// quit, are then guaranteed to observe it regardless of where they are ordered relative to
+    /// the system that sent it.
+    ///
+    /// Defaults to `false`, exiting right after the update that sent the event.
+    #[cfg(feature = "events")]
+    pub defer_exit: bool,
 }
 
 impl ScheduleRunnerPlugin {
@@ -54,6 +162,8 @@ impl ScheduleRunnerPlugin {
     pub fn run_once() -> Self {
         ScheduleRunnerPlugin {
             run_mode: RunMode::Once,
+            #[cfg(feature = "events")]
+            defer_exit: false,
         }
     }
 
@@ -61,23 +171,60 @@ impl ScheduleRunnerPlugin {
     pub fn run_loop() -> Self {
         ScheduleRunnerPlugin {
             run_mode: RunMode::Loop,
+            #[cfg(feature = "events")]
+            defer_exit: false,
         }
     }
 
-    /// See [`RunMode::Loop`].
+    /// See [`RunMode::LoopWait`]. Waits with [`WaitStrategy::Sleep`].
     #[cfg(feature = "loop_wait")]
     pub fn run_loop_wait(wait_duration: Duration) -> Self {
+        Self::run_loop_wait_with(wait_duration, WaitStrategy::Sleep)
+    }
+
+    /// See [`RunMode::LoopWait`]. Waits with the given [`WaitStrategy`].
+    #[cfg(feature = "loop_wait")]
+    pub fn run_loop_wait_with(wait_duration: Duration, strategy: WaitStrategy) -> Self {
         ScheduleRunnerPlugin {
             run_mode: RunMode::LoopWait {
                 wait: wait_duration,
+                strategy,
             },
+            #[cfg(feature = "events")]
+            defer_exit: false,
+        }
+    }
+
+    /// See [`RunMode::LoopRate`]. Waits with [`WaitStrategy::Sleep`].
+    #[cfg(feature = "loop_wait")]
+    pub fn run_loop_rate(target: Duration) -> Self {
+        Self::run_loop_rate_with(target, WaitStrategy::Sleep)
+    }
+
+    /// See [`RunMode::LoopRate`]. Waits with the given [`WaitStrategy`].
+    #[cfg(feature = "loop_wait")]
+    pub fn run_loop_rate_with(target: Duration, strategy: WaitStrategy) -> Self {
+        ScheduleRunnerPlugin {
+            run_mode: RunMode::LoopRate { target, strategy },
+            #[cfg(feature = "events")]
+            defer_exit: false,
         }
     }
+
+    /// Runs one more update after an [`AppExit`] is first seen. See
+    /// [`ScheduleRunnerPlugin::defer_exit`].
+    #[cfg(feature = "events")]
+    pub fn with_deferred_exit(mut self) -> Self {
+        self.defer_exit = true;
+        self
+    }
 }
 
 impl Plugin for ScheduleRunnerPlugin {
     fn build(&self, app: &mut App) {
         let run_mode = self.run_mode;
+        #[cfg(feature = "events")]
+        let defer_exit = self.defer_exit;
         app.set_runner(move |mut app: App| {
             let plugins_state = app.plugins_state();
             if plugins_state != PluginsState::Cleaned {
@@ -92,18 +239,37 @@ impl Plugin for ScheduleRunnerPlugin {
             let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
 
             match run_mode {
-                RunMode::Once => app.update(),
+                RunMode::Once => {
+                    app.update();
+
+                    #[cfg(feature = "events")]
+                    if let Some(app_exit_events) = app.world().get_resource::<Events<AppExit>>() {
+                        if let Some(&exit) = app_exit_event_reader.read(app_exit_events).last() {
+                            app.shutdown();
+                            return exit;
+                        }
+                    }
+
+                    AppExit::Success
+                }
                 RunMode::Loop => loop {
                     app.update();
                     #[cfg(feature = "events")]
-                    if let Some(app_exit_events) = app.world.get_resource_mut::<Events<AppExit>>() {
+                    if let Some(app_exit_events) =
+                        app.world_mut().get_resource_mut::<Events<AppExit>>()
+                    {
                         if let Some(exit) = app_exit_event_reader.read(&app_exit_events).last() {
-                            break;
+                            let exit = *exit;
+                            if defer_exit {
+                                app.update();
+                            }
+                            app.shutdown();
+                            break exit;
                         }
                     }
                 },
                 #[cfg(feature = "loop_wait")]
-                RunMode::LoopWait { wait } => {
+                RunMode::LoopWait { wait, strategy } => {
                     let mut tick = move |app: &mut App,
                                          wait: Duration|
                           -> Result<Option<Duration>, AppExit> {
@@ -113,11 +279,11 @@ impl Plugin for ScheduleRunnerPlugin {
 
                         #[cfg(feature = "events")]
                         if let Some(app_exit_events) =
-                            app.world.get_resource_mut::<Events<AppExit>>()
+                            app.world_mut().get_resource_mut::<Events<AppExit>>()
                         {
                             if let Some(exit) = app_exit_event_reader.read(&app_exit_events).last()
                             {
-                                return Err(exit.clone());
+                                return Err(*exit);
                             }
                         }
 
@@ -130,13 +296,96 @@ impl Plugin for ScheduleRunnerPlugin {
                         Ok(None)
                     };
 
-                    while let Ok(delay) = tick(&mut app, wait) {
-                        if let Some(delay) = delay {
-                            std::thread::sleep(delay);
+                    let exit = loop {
+                        match tick(&mut app, wait) {
+                            Ok(Some(delay)) => strategy.wait(delay),
+                            Ok(None) => {}
+                            Err(exit) => break exit,
                         }
+                    };
+
+                    #[cfg(feature = "events")]
+                    {
+                        if defer_exit {
+                            app.update();
+                        }
+                        app.shutdown();
                     }
+
+                    exit
+                }
+                #[cfg(feature = "loop_wait")]
+                RunMode::LoopRate { target, strategy } => {
+                    let mut pacer = FramePacer::new(target, Instant::now());
+                    let exit = loop {
+                        app.update();
+
+                        #[cfg(feature = "events")]
+                        if let Some(app_exit_events) =
+                            app.world_mut().get_resource_mut::<Events<AppExit>>()
+                        {
+                            if let Some(exit) = app_exit_event_reader.read(&app_exit_events).last()
+                            {
+                                break *exit;
+                            }
+                        }
+
+                        if let Some(wait) = pacer.frame_ended(Instant::now()) {
+                            strategy.wait(wait);
+                        }
+                    };
+
+                    #[cfg(feature = "events")]
+                    {
+                        if defer_exit {
+                            app.update();
+                        }
+                        app.shutdown();
+                    }
+
+                    exit
                 }
             }
         });
     }
 }
+
+#[cfg(all(test, feature = "loop_wait"))]
+mod tests {
+    use super::FramePacer;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn frame_pacer_converges_on_target() {
+        let target = Duration::from_millis(10);
+        let origin = Instant::now();
+        let mut pacer = FramePacer::new(target, origin);
+
+        // Updates of varying length, and sleeps that overshoot by up to 2ms.
+        let mut start = origin;
+        let frames = 100;
+        for frame in 0..frames {
+            let end = start + Duration::from_millis(2 + frame % 5);
+            let sleep = pacer.frame_ended(end).unwrap();
+            start = end + sleep + Duration::from_millis(frame % 3);
+        }
+
+        let average = (start - origin) / frames as u32;
+        let error = average.abs_diff(target);
+        assert!(error < Duration::from_micros(50), "average was {average:?}");
+    }
+
+    #[test]
+    fn frame_pacer_resets_after_overrun() {
+        let target = Duration::from_millis(10);
+        let origin = Instant::now();
+        let mut pacer = FramePacer::new(target, origin);
+
+        let overrun_end = origin + Duration::from_millis(35);
+        assert_eq!(pacer.frame_ended(overrun_end), None);
+
+        // The next frame starts right away and gets a full target from there.
+        let end = overrun_end + Duration::from_millis(4);
+        assert_eq!(pacer.frame_ended(end), Some(Duration::from_millis(6)));
+    }
+}
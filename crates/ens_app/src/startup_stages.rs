@@ -0,0 +1,58 @@
+use std::collections::BTreeSet;
+
+use ens::{access::Mut, schedule::ScheduleLabel, system::Resource, world::World};
+
+/// A startup sub-stage, run by [`RunStartupStages`](crate::RunStartupStages) in index order.
+///
+/// Add systems to a stage with [`App::add_startup_stage_systems`](crate::App::add_startup_stage_systems),
+/// which also registers it, so that it counts towards [`StartupProgress::total`].
+#[derive(ScheduleLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StartupStage(pub usize);
+
+/// How many [`StartupStage`]s have finished running.
+///
+/// This is updated after each stage, during the first run of the [`Main`](crate::Main)
+/// schedule, so a loading screen can show e.g. "Loading: stage 3/7". Register a callback with
+/// [`App::on_startup_progress`](crate::App::on_startup_progress) to be notified as well.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StartupProgress {
+    /// The number of stages that have finished.
+    pub completed: usize,
+    /// The number of registered stages.
+    pub total: usize,
+}
+
+/// A callback invoked after each [`StartupStage`] finishes.
+pub type StartupProgressFn = Box<dyn FnMut(&mut World, StartupProgress) + Send + Sync>;
+
+/// The registered [`StartupStage`]s and progress callbacks.
+#[derive(Resource, Default)]
+pub(crate) struct StartupStages {
+    pub(crate) stages: BTreeSet<usize>,
+    pub(crate) callbacks: Vec<StartupProgressFn>,
+}
+
+/// Runs every registered [`StartupStage`] in index order, updating [`StartupProgress`] and
+/// calling the progress callbacks after each one.
+pub(crate) fn run_startup_stages(world: &mut World) {
+    world.resource_scope(|world, mut stages: Mut<StartupStages>| {
+        let total = stages.stages.len();
+        let indices: Vec<_> = stages.stages.iter().copied().collect();
+        *world.resource_mut::<StartupProgress>() = StartupProgress {
+            completed: 0,
+            total,
+        };
+        for (completed, index) in indices.into_iter().enumerate() {
+            let _ = world.try_run_schedule(StartupStage(index));
+
+            let progress = StartupProgress {
+                completed: completed + 1,
+                total,
+            };
+            *world.resource_mut::<StartupProgress>() = progress;
+            for callback in &mut stages.callbacks {
+                callback(world, progress);
+            }
+        }
+    });
+}
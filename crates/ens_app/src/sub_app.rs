@@ -0,0 +1,90 @@
+use ens::{
+    schedule::{InternedScheduleLabel, IntoSystemConfigs, Schedule, ScheduleLabel, Schedules},
+    world::World,
+};
+use ens_utils::{define_label, intern::Interned};
+
+// The `AppLabel` derive refers to `ens_app::DynEq`.
+#[doc(hidden)]
+pub use ens_utils::label::DynEq;
+
+define_label!(
+    /// A strongly-typed class of labels used to identify a [`SubApp`].
+    AppLabel,
+    APP_LABEL_INTERNER
+);
+
+/// A shorthand for `Interned<dyn AppLabel>`.
+pub type InternedAppLabel = Interned<dyn AppLabel>;
+
+/// The function a [`SubApp`] uses to copy data out of the main [`World`], given the main world
+/// first and the sub-app's world second.
+pub type ExtractFn = Box<dyn Fn(&mut World, &mut World) + Send>;
+
+/// A secondary [`World`] with its own schedule, advanced by the [`App`](crate::App) it was
+/// [inserted](crate::App::insert_sub_app) into.
+///
+/// After the main schedule of each [`App::update`](crate::App::update), the [`extract`]
+/// function is called with mutable access to both worlds, then the [`schedule_label`] schedule
+/// is run on the sub-app's world. This allows e.g. splitting rendering from game logic, with the
+/// extract function as the only synchronization point between the two.
+///
+/// [`extract`]: SubApp::extract
+/// [`schedule_label`]: SubApp::schedule_label
+pub struct SubApp {
+    /// The [`World`] of this sub-app.
+    pub world: World,
+    /// The schedule run on [`world`](SubApp::world) each update.
+    pub schedule_label: InternedScheduleLabel,
+    /// Copies data from the main [`World`] into the sub-app's [`World`] before its schedule runs.
+    pub extract: ExtractFn,
+}
+
+impl std::fmt::Debug for SubApp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubApp")
+            .field("schedule_label", &self.schedule_label)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SubApp {
+    /// Creates a sub-app with an empty [`World`] that runs the `schedule_label` schedule, which is
+    /// created empty.
+    pub fn new(
+        schedule_label: impl ScheduleLabel,
+        extract: impl Fn(&mut World, &mut World) + Send + 'static,
+    ) -> Self {
+        let schedule_label = schedule_label.intern();
+        let mut world = World::new();
+        world.init_resource::<Schedules>();
+        world.add_schedule(Schedule::new(schedule_label));
+        Self {
+            world,
+            schedule_label,
+            extract: Box::new(extract),
+        }
+    }
+
+    /// Adds systems to the schedule of this sub-app.
+    pub fn add_systems<M>(&mut self, systems: impl IntoSystemConfigs<M>) -> &mut Self {
+        let mut schedules = self.world.resource_mut::<Schedules>();
+        if let Some(schedule) = schedules.get_mut(self.schedule_label) {
+            schedule.add_systems(systems);
+        } else {
+            let mut new_schedule = Schedule::new(self.schedule_label);
+            new_schedule.add_systems(systems);
+            schedules.insert(new_schedule);
+        }
+
+        self
+    }
+
+    /// Runs the [`extract`](SubApp::extract) function, then the schedule of this sub-app, then
+    /// clears the change trackers of its [`World`].
+    pub fn run(&mut self, main_world: &mut World) {
+        (self.extract)(main_world, &mut self.world);
+        self.world.run_schedule(self.schedule_label);
+        self.world.clear_trackers();
+    }
+}
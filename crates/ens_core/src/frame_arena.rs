@@ -0,0 +1,238 @@
+use ens::prelude::*;
+use ens_app::{App, Plugin, PreUpdate};
+
+use std::{
+    alloc::{self, Layout},
+    ptr::{self, NonNull},
+    sync::Mutex,
+};
+
+/// The alignment of every chunk backing a [`FrameArena`].
+const CHUNK_ALIGN: usize = 16;
+
+/// Adds a [`FrameArena`] that is reset at the start of every frame.
+///
+/// The reset runs in [`PreUpdate`], the first schedule of each frame, inside [`FrameArenaSystem`].
+/// Systems in [`PreUpdate`] that allocate from the arena should run after that set.
+pub struct FrameArenaPlugin {
+    /// The number of bytes the arena reserves up front.
+    pub initial_capacity: usize,
+}
+
+impl Default for FrameArenaPlugin {
+    fn default() -> Self {
+        Self {
+            initial_capacity: FrameArena::DEFAULT_CAPACITY,
+        }
+    }
+}
+
+impl Plugin for FrameArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(FrameArena::with_capacity(self.initial_capacity))
+            .add_systems(PreUpdate, reset_frame_arena.in_set(FrameArenaSystem));
+    }
+}
+
+/// Resets the [`FrameArena`]. Any system allocating from the arena in [`PreUpdate`] should run
+/// after this.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct FrameArenaSystem;
+
+/// The system used to reset the [`FrameArena`] once per frame.
+pub fn reset_frame_arena(mut arena: ResMut<FrameArena>) {
+    arena.reset();
+}
+
+/// A bump allocator for transient, per-frame data.
+///
+/// Allocating from the arena only bumps an offset, so systems that would otherwise build a
+/// temporary [`Vec`] or [`String`] every frame can borrow scratch memory from
+/// `Res<FrameArena>` instead. Everything allocated is released at once by [`FrameArena::reset`],
+/// which [`FrameArenaPlugin`] schedules at the start of every frame. When a frame outgrows the
+/// arena, a new chunk is added, and the next reset merges all chunks into one so that later
+/// frames of the same size don't allocate at all.
+///
+/// Only [`Copy`] types can be allocated, since values are never dropped.
+///
+/// # Lifetimes
+///
+/// Allocations borrow the arena, so the borrow checker already prevents them from outliving the
+/// `Res<FrameArena>` they came from. Data that must survive the frame boundary has to be copied
+/// out (e.g. with `to_vec` or `to_owned`) before the system returns; extending the lifetime of an
+/// allocation through raw pointers is undefined behaviour once the arena is reset.
+#[derive(Resource)]
+pub struct FrameArena {
+    chunks: Mutex<Chunks>,
+}
+
+impl Default for FrameArena {
+    fn default() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl FrameArena {
+    /// The number of bytes reserved by [`FrameArena::default`].
+    pub const DEFAULT_CAPACITY: usize = 64 * 1024;
+
+    /// Creates an arena that can hold `capacity` bytes before growing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            chunks: Mutex::new(Chunks {
+                chunks: vec![Chunk::new(capacity)],
+                offset: 0,
+            }),
+        }
+    }
+
+    /// Moves `value` into the arena and returns a mutable reference to it.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: Copy>(&self, value: T) -> &mut T {
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        // SAFETY: `ptr` is valid for writes of `T`, properly aligned and not handed out to anyone else
+        // until the arena is reset, which requires `&mut self`.
+        unsafe {
+            ptr.as_ptr().write(value);
+            &mut *ptr.as_ptr()
+        }
+    }
+
+    /// Copies `src` into the arena and returns a mutable reference to the copy.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        let ptr = self
+            .alloc_layout(Layout::for_value(src))
+            .cast::<T>()
+            .as_ptr();
+        // SAFETY: `ptr` is valid for `src.len()` writes of `T`, properly aligned, and cannot overlap
+        // `src` because it was just allocated.
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), ptr, src.len());
+            std::slice::from_raw_parts_mut(ptr, src.len())
+        }
+    }
+
+    /// Allocates a slice of `len` elements, initializing the element at each index with `f(index)`.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_with<T: Copy>(
+        &self,
+        len: usize,
+        mut f: impl FnMut(usize) -> T,
+    ) -> &mut [T] {
+        let layout = Layout::array::<T>(len).expect("FrameArena allocation too large");
+        let ptr = self.alloc_layout(layout).cast::<T>().as_ptr();
+        for index in 0..len {
+            // SAFETY: `ptr` is valid for `len` writes of `T` and properly aligned.
+            unsafe { ptr.add(index).write(f(index)) };
+        }
+        // SAFETY: All `len` elements were initialized above.
+        unsafe { std::slice::from_raw_parts_mut(ptr, len) }
+    }
+
+    /// Copies `src` into the arena and returns a reference to the copy.
+    pub fn alloc_str(&self, src: &str) -> &str {
+        let bytes = self.alloc_slice_copy(src.as_bytes());
+        // SAFETY: The bytes were copied from a valid `str`.
+        unsafe { std::str::from_utf8_unchecked(bytes) }
+    }
+
+    /// The number of bytes allocated since the last reset, including alignment padding.
+    pub fn allocated_bytes(&self) -> usize {
+        let chunks = self.chunks.lock().unwrap();
+        let (_, previous) = chunks.chunks.split_last().unwrap();
+        previous.iter().map(|chunk| chunk.capacity).sum::<usize>() + chunks.offset
+    }
+
+    /// The number of bytes the arena can hold before growing.
+    pub fn capacity(&self) -> usize {
+        let chunks = self.chunks.lock().unwrap();
+        chunks.chunks.iter().map(|chunk| chunk.capacity).sum()
+    }
+
+    /// Releases every allocation made since the last reset.
+    ///
+    /// If the arena had to grow during the frame, its chunks are merged into a single chunk
+    /// large enough to hold all of them.
+    pub fn reset(&mut self) {
+        let chunks = self.chunks.get_mut().unwrap();
+        if chunks.chunks.len() > 1 {
+            let capacity = chunks.chunks.iter().map(|chunk| chunk.capacity).sum();
+            chunks.chunks = vec![Chunk::new(capacity)];
+        }
+        chunks.offset = 0;
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        if layout.size() == 0 {
+            // SAFETY: `align` is never zero.
+            return unsafe { NonNull::new_unchecked(layout.align() as *mut u8) };
+        }
+
+        let mut chunks = self.chunks.lock().unwrap();
+        if let Some(ptr) = chunks.try_alloc(layout) {
+            return ptr;
+        }
+
+        let last_capacity = chunks.chunks.last().map_or(0, |chunk| chunk.capacity);
+        let capacity = (last_capacity * 2).max(layout.size() + layout.align());
+        chunks.chunks.push(Chunk::new(capacity));
+        chunks.offset = 0;
+        chunks
+            .try_alloc(layout)
+            .expect("a fresh FrameArena chunk must fit the allocation")
+    }
+}
+
+struct Chunks {
+    chunks: Vec<Chunk>,
+    /// The number of bytes used in the last chunk.
+    offset: usize,
+}
+
+impl Chunks {
+    fn try_alloc(&mut self, layout: Layout) -> Option<NonNull<u8>> {
+        let chunk = self.chunks.last()?;
+        let base = chunk.ptr.as_ptr() as usize;
+        let start = (base + self.offset).checked_next_multiple_of(layout.align())? - base;
+        let end = start.checked_add(layout.size())?;
+        if end > chunk.capacity {
+            return None;
+        }
+        self.offset = end;
+        // SAFETY: `start < end <= capacity`, so the pointer stays within the chunk's allocation.
+        Some(unsafe { NonNull::new_unchecked(chunk.ptr.as_ptr().add(start)) })
+    }
+}
+
+/// An owned, uninitialized allocation. Only ever accessed through raw pointers, so handing out
+/// references into it never aliases a reference to the chunk itself.
+struct Chunk {
+    ptr: NonNull<u8>,
+    capacity: usize,
+}
+
+// SAFETY: `Chunk` uniquely owns its allocation, just like a `Box<[u8]>`.
+unsafe impl Send for Chunk {}
+
+impl Chunk {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let layout = Self::layout(capacity);
+        // SAFETY: `layout` has a non-zero size.
+        let ptr = unsafe { alloc::alloc(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        Self { ptr, capacity }
+    }
+
+    fn layout(capacity: usize) -> Layout {
+        Layout::from_size_align(capacity, CHUNK_ALIGN).expect("FrameArena chunk too large")
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated in `Chunk::new` with this exact layout.
+        unsafe { alloc::dealloc(self.ptr.as_ptr(), Self::layout(self.capacity)) };
+    }
+}
@@ -0,0 +1,86 @@
+use crate::FrameCountPlugin;
+use ens_app::{App, ScheduleRunnerPlugin};
+use ens_time::TimePlugin;
+
+use std::time::Duration;
+
+/// An extension trait for [`App`] that builds apps without a window, such as servers.
+///
+/// Requires the `headless` feature of `ens_core`, which enables `frame_count` along with the
+/// `events` features of `ens_app` and `ens_time` and the `loop_wait` feature of `ens_app`.
+pub trait HeadlessAppExt {
+    /// Creates an [`App`] that updates `tick_hz` times per second until an
+    /// [`AppExit`](ens_app::AppExit) is sent.
+    ///
+    /// The app comes with [`TimePlugin`], a [`FrameCountPlugin`] and a
+    /// [`ScheduleRunnerPlugin`] in [`RunMode::LoopRate`](ens_app::RunMode::LoopRate) mode, which
+    /// starts an update every `1 / tick_hz` seconds. More plugins can be added before calling
+    /// [`App::run`].
+    ///
+    /// No task pools are set up: `ens_app` doesn't build its `TaskPoolPlugin` in the
+    /// configuration `headless` depends on. Apps that spawn tasks should initialize the pools
+    /// they use, e.g. with `IoTaskPool::get_or_init`, before calling [`App::run`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_hz` is not a positive, finite number.
+    fn headless(tick_hz: f64) -> App;
+}
+
+impl HeadlessAppExt for App {
+    fn headless(tick_hz: f64) -> App {
+        assert!(
+            tick_hz.is_finite() && tick_hz > 0.0,
+            "tick rate must be positive and finite, got {tick_hz}"
+        );
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            FrameCountPlugin,
+            ScheduleRunnerPlugin::run_loop_rate(Duration::from_secs_f64(1.0 / tick_hz)),
+        ));
+        app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FrameCount;
+    use ens::{event::EventWriter, prelude::*};
+    use ens_app::{AppExit, Update};
+    use ens_time::{Real, Time};
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn headless_app_ticks_until_exit() {
+        let mut app = App::headless(200.0);
+        assert!(app.world.contains_resource::<Time<Real>>());
+        assert!(app.world.contains_resource::<FrameCount>());
+
+        let frames = Arc::new(AtomicU32::new(0));
+        let counted = frames.clone();
+        app.add_systems(
+            Update,
+            move |frame_count: Res<FrameCount>, mut exit: EventWriter<AppExit>| {
+                counted.store(frame_count.0, Ordering::Relaxed);
+                if frame_count.0 == 3 {
+                    exit.send(AppExit::Success);
+                }
+            },
+        );
+        app.run();
+
+        assert_eq!(frames.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn headless_rejects_zero_tick_rate() {
+        App::headless(0.0);
+    }
+}
@@ -1,24 +1,57 @@
 //! This crate provides core functionality for Bevy Engine.
 
+#[cfg(feature = "frame_arena")]
+mod frame_arena;
+#[cfg(feature = "headless")]
+mod headless;
 #[cfg(feature = "entity_name")]
 mod name;
+#[cfg(feature = "entity_name")]
+mod name_registry;
+mod optional_name;
+#[cfg(feature = "entity_name")]
+mod prefab;
 #[cfg(all(feature = "entity_name", feature = "serialize"))]
 mod serde;
 
 use ens::system::Resource;
+#[cfg(feature = "frame_count")]
+use std::marker::PhantomData;
 
+#[cfg(feature = "frame_arena")]
+pub use frame_arena::*;
+#[cfg(feature = "headless")]
+pub use headless::*;
 #[cfg(feature = "entity_name")]
 pub use name::*;
+#[cfg(feature = "entity_name")]
+pub use name_registry::*;
+pub use optional_name::*;
+#[cfg(feature = "entity_name")]
+pub use prefab::*;
 
 pub mod prelude {
     //! The Bevy Core Prelude.
+    #[doc(hidden)]
+    pub use crate::OptionalName;
+
     #[doc(hidden)]
     #[cfg(feature = "frame_count")]
-    pub use FrameCountPlugin;
+    pub use crate::{FrameCount, FrameCountPlugin, FrameCountSystem, TaggedFrameCountPlugin};
 
     #[doc(hidden)]
     #[cfg(feature = "entity_name")]
-    pub use crate::{DebugName, Name};
+    pub use crate::{
+        DebugName, Name, NameRegistry, NameRegistryPlugin, PrefabCommandsExt, PrefabRegistry,
+    };
+
+    #[doc(hidden)]
+    #[cfg(feature = "frame_arena")]
+    pub use crate::{FrameArena, FrameArenaPlugin};
+
+    #[doc(hidden)]
+    #[cfg(feature = "headless")]
+    pub use crate::HeadlessAppExt;
 }
 
 use ens::prelude::*;
@@ -26,19 +59,102 @@ use ens_app::prelude::*;
 
 /// Maintains a count of frames rendered since the start of the application.
 ///
-/// [`FrameCount`] is incremented during [`Last`], providing predictable
+/// [`FrameCount`] is incremented during [`PostUpdate`], providing predictable
 /// behavior: it will be 0 during the first update, 1 during the next, and so forth.
 ///
+/// # Tagged counters
+///
+/// `Tag` is a marker type that lets an app keep several independent counters. The untagged
+/// `FrameCount` (`FrameCount<()>`) is added by [`FrameCountPlugin`], every other counter by a
+/// [`TaggedFrameCountPlugin<Tag>`]. A counter that should only advance while some part of the
+/// app is active can be gated with a run condition on its [`FrameCountSystem<Tag>`]:
+///
+/// ```ignore
+/// struct Gameplay;
+///
+/// app.add_plugins(TaggedFrameCountPlugin::<Gameplay>::default())
+///     .configure_sets(PostUpdate, FrameCountSystem::<Gameplay>::default().run_if(in_gameplay));
+/// ```
+///
+/// Because of the marker field, counters are created with [`FrameCount::new`] (or
+/// [`FrameCount::tagged`]) rather than the tuple constructor. The count itself is still `.0`.
+///
 /// # Overflows
 ///
 /// [`FrameCount`] will wrap to 0 after exceeding [`u32::MAX`]. Within reasonable
 /// assumptions, one may exploit wrapping arithmetic to determine the number of frames
-/// that have elapsed between two observations – see [`u32::wrapping_sub()`].
+/// that have elapsed between two observations – see [`FrameCount::frames_since`].
 #[cfg(feature = "frame_count")]
-#[derive(Debug, Default, Resource, Clone, Copy)]
-pub struct FrameCount(pub u32);
+#[derive(Resource)]
+pub struct FrameCount<Tag: Send + Sync + 'static = ()>(pub u32, PhantomData<Tag>);
+
+#[cfg(feature = "frame_count")]
+impl FrameCount {
+    /// Creates an untagged counter starting at `count`.
+    pub const fn new(count: u32) -> Self {
+        Self::tagged(count)
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> FrameCount<Tag> {
+    /// Creates a counter for `Tag` starting at `count`.
+    pub const fn tagged(count: u32) -> Self {
+        Self(count, PhantomData)
+    }
+
+    /// Returns the number of frames elapsed between `earlier` and `self`, accounting for the
+    /// counter wrapping to 0 after [`u32::MAX`].
+    ///
+    /// If more than [`u32::MAX`] frames elapsed, the result is off by a multiple of
+    /// `u32::MAX + 1`. Use [`FrameCount::checked_frames_since`] to detect suspicious gaps.
+    pub const fn frames_since(&self, earlier: FrameCount<Tag>) -> u32 {
+        self.0.wrapping_sub(earlier.0)
+    }
+
+    /// Like [`FrameCount::frames_since`], but returns `None` if more than `u32::MAX / 2` frames
+    /// appear to have elapsed.
+    ///
+    /// Such a gap can't be told apart from `earlier` actually being later than `self`, e.g. when
+    /// the observations were swapped.
+    pub const fn checked_frames_since(&self, earlier: FrameCount<Tag>) -> Option<u32> {
+        let frames = self.frames_since(earlier);
+        if frames > u32::MAX / 2 {
+            None
+        } else {
+            Some(frames)
+        }
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Default for FrameCount<Tag> {
+    fn default() -> Self {
+        Self::tagged(0)
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Clone for FrameCount<Tag> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Copy for FrameCount<Tag> {}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> std::fmt::Debug for FrameCount<Tag> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FrameCount").field(&self.0).finish()
+    }
+}
 
 /// Adds frame counting functionality to Apps.
+///
+/// This adds the untagged [`FrameCount`]; use [`TaggedFrameCountPlugin<Tag>`] for a
+/// [`FrameCount<Tag>`].
 #[cfg(feature = "frame_count")]
 #[derive(Default)]
 pub struct FrameCountPlugin;
@@ -46,8 +162,73 @@ pub struct FrameCountPlugin;
 #[cfg(feature = "frame_count")]
 impl Plugin for FrameCountPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<FrameCount>();
-        app.add_systems(Last, update_frame_count);
+        TaggedFrameCountPlugin::<()>::default().build(app);
+    }
+}
+
+/// Adds a [`FrameCount<Tag>`], incremented by [`update_tagged_frame_count::<Tag>`] inside
+/// [`FrameCountSystem<Tag>`].
+#[cfg(feature = "frame_count")]
+pub struct TaggedFrameCountPlugin<Tag: Send + Sync + 'static>(PhantomData<Tag>);
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Default for TaggedFrameCountPlugin<Tag> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Plugin for TaggedFrameCountPlugin<Tag> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameCount<Tag>>();
+        app.add_systems(
+            PostUpdate,
+            update_tagged_frame_count::<Tag>.in_set(FrameCountSystem::<Tag>::default()),
+        );
+    }
+}
+
+/// The [`SystemSet`] containing the system that increments [`FrameCount<Tag>`].
+///
+/// Configure this set with a run condition to only count frames while it holds.
+#[cfg(feature = "frame_count")]
+#[derive(SystemSet)]
+pub struct FrameCountSystem<Tag: Send + Sync + 'static = ()>(PhantomData<Tag>);
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Default for FrameCountSystem<Tag> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Clone for FrameCountSystem<Tag> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> PartialEq for FrameCountSystem<Tag> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> Eq for FrameCountSystem<Tag> {}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> std::hash::Hash for FrameCountSystem<Tag> {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(feature = "frame_count")]
+impl<Tag: Send + Sync + 'static> std::fmt::Debug for FrameCountSystem<Tag> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FrameCountSystem<{}>", std::any::type_name::<Tag>())
     }
 }
 
@@ -55,7 +236,15 @@ impl Plugin for FrameCountPlugin {
 ///
 /// See [`FrameCount`] for more details.
 #[cfg(feature = "frame_count")]
-pub fn update_frame_count(mut frame_count: ResMut<FrameCount>) {
+pub fn update_frame_count(frame_count: ResMut<FrameCount>) {
+    update_tagged_frame_count(frame_count);
+}
+
+/// A system used to increment [`FrameCount<Tag>`] with wrapping addition.
+#[cfg(feature = "frame_count")]
+pub fn update_tagged_frame_count<Tag: Send + Sync + 'static>(
+    mut frame_count: ResMut<FrameCount<Tag>>,
+) {
     frame_count.0 = frame_count.0.wrapping_add(1);
 }
 
@@ -106,4 +295,85 @@ mod tests {
         let frame_count = app.world.resource::<FrameCount>();
         assert_eq!(1, frame_count.0);
     }
+
+    #[cfg(feature = "frame_count")]
+    #[test]
+    fn tagged_frame_counters_are_independent() {
+        #[derive(Resource, Default)]
+        struct InGameplay(bool);
+        struct Gameplay;
+
+        let mut app = App::new();
+        app.init_resource::<InGameplay>()
+            .add_plugins((
+                FrameCountPlugin,
+                TaggedFrameCountPlugin::<Gameplay>::default(),
+            ))
+            .configure_sets(
+                PostUpdate,
+                FrameCountSystem::<Gameplay>::default()
+                    .run_if(|in_gameplay: Res<InGameplay>| in_gameplay.0),
+            );
+
+        app.update();
+        app.world.resource_mut::<InGameplay>().0 = true;
+        app.update();
+        app.update();
+
+        assert_eq!(app.world.resource::<FrameCount>().0, 3);
+        assert_eq!(app.world.resource::<FrameCount<Gameplay>>().0, 2);
+    }
+
+    #[cfg(feature = "frame_count")]
+    #[test]
+    fn frames_since_wraps_at_u32_max() {
+        let max = FrameCount::new(u32::MAX);
+        let zero = FrameCount::new(0);
+
+        assert_eq!(zero.frames_since(max), 1);
+        assert_eq!(max.frames_since(max), 0);
+        assert_eq!(
+            FrameCount::new(4).frames_since(FrameCount::new(u32::MAX - 5)),
+            10
+        );
+        assert_eq!(max.frames_since(zero), u32::MAX);
+
+        assert_eq!(zero.checked_frames_since(max), Some(1));
+        assert_eq!(
+            FrameCount::new(u32::MAX / 2).checked_frames_since(zero),
+            Some(u32::MAX / 2)
+        );
+        assert_eq!(
+            FrameCount::new(u32::MAX / 2 + 1).checked_frames_since(zero),
+            None
+        );
+        assert_eq!(max.checked_frames_since(zero), None);
+        assert_eq!(zero.checked_frames_since(FrameCount::new(1)), None);
+    }
+
+    #[cfg(feature = "frame_arena")]
+    #[test]
+    fn frame_arena_resets_each_frame() {
+        let mut app = App::new();
+        app.add_plugins(FrameArenaPlugin {
+            initial_capacity: 16,
+        });
+
+        {
+            let arena = app.world.resource::<FrameArena>();
+            let values = arena.alloc_slice_fill_with(8, |i| i as u32);
+            assert_eq!(values, &[0, 1, 2, 3, 4, 5, 6, 7]);
+            assert_eq!(arena.alloc_str("transient"), "transient");
+        }
+        let arena = app.world.resource::<FrameArena>();
+        assert!(arena.allocated_bytes() >= 32 + 9);
+        let grown_capacity = arena.capacity();
+        assert!(grown_capacity > 16);
+
+        app.update();
+
+        let arena = app.world.resource::<FrameArena>();
+        assert_eq!(arena.allocated_bytes(), 0);
+        assert_eq!(arena.capacity(), grown_capacity);
+    }
 }
@@ -1,15 +1,28 @@
 use ens::query::QueryData;
 use ens::{component::Component, entity::Entity};
 
-use ens_utils::AHasher;
+use ens_utils::{hashbrown::Equivalent, intern::Interner, HashSet};
 use std::{
     borrow::Cow,
     hash::{Hash, Hasher},
     ops::Deref,
+    sync::{Arc, OnceLock, PoisonError, RwLock},
 };
+use thiserror::Error;
+
+/// The interner for names created from a `&'static str`, which never have to be freed.
+static STATIC_NAMES: Interner<str> = Interner::new();
+
+/// The strings of names created from owned strings, shared between equal names.
+static SHARED_NAMES: SharedNames = SharedNames(OnceLock::new());
 
 /// Component used to identify an entity. Stores a hash for faster comparisons.
 ///
+/// Identical names usually share a single string, which makes [`Name`] cheap to clone and store
+/// in bulk. Names created from a `&'static str` point to it directly, while names created from
+/// owned strings are reference counted and freed once no [`Name`] uses them anymore, so names
+/// from unbounded or untrusted data (e.g. counters or client input) don't leak memory.
+///
 /// The hash is eagerly re-computed upon each update to the name.
 ///
 /// [`Name`] should not be treated as a globally unique identifier for entities,
@@ -18,7 +31,79 @@ use std::{
 #[derive(Component, Clone)]
 pub struct Name {
     hash: u64, // Won't be serialized (see: `ens_core::serde` module)
-    name: Cow<'static, str>,
+    name: NameStr,
+}
+
+#[derive(Clone)]
+enum NameStr {
+    Static(&'static str),
+    Shared(Arc<str>),
+}
+
+/// The set of shared name strings.
+///
+/// Strings only referenced by the set are removed each time it doubles in size, which keeps it
+/// within twice the number of strings in use.
+struct SharedNames(OnceLock<RwLock<SharedNameSet>>);
+
+#[derive(Default)]
+struct SharedNameSet {
+    names: HashSet<Arc<str>>,
+    prune_at: usize,
+}
+
+impl SharedNames {
+    /// The size the set grows to before it is first pruned.
+    const MIN_PRUNE_AT: usize = 64;
+
+    fn get(&self, name: &str) -> Option<Arc<str>> {
+        let set = self.0.get()?.read().unwrap_or_else(PoisonError::into_inner);
+        set.names.get(name).cloned()
+    }
+
+    fn intern(&self, name: String) -> Arc<str> {
+        if let Some(name) = self.get(&name) {
+            return name;
+        }
+        let lock = self.0.get_or_init(Default::default);
+        let mut set = lock.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(name) = set.names.get(name.as_str()) {
+            return name.clone();
+        }
+        if set.names.len() >= set.prune_at {
+            set.names.retain(|name| Arc::strong_count(name) > 1);
+            set.prune_at = (set.names.len() * 2).max(Self::MIN_PRUNE_AT);
+        }
+        let name: Arc<str> = name.into();
+        set.names.insert(name.clone());
+        name
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.0.get().map_or(0, |lock| {
+            lock.read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .names
+                .len()
+        })
+    }
+}
+
+/// An error returned by [`Name::new_validated`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum NameError {
+    /// The name is longer than the allowed number of characters.
+    #[error("name is {len} characters long, exceeding the maximum of {max_len}")]
+    TooLong {
+        /// The number of characters in the rejected name.
+        len: usize,
+        /// The maximum number of characters allowed.
+        max_len: usize,
+    },
+    /// The name contains a control character.
+    #[error("name contains the control character {0:?}")]
+    ControlCharacter(char),
 }
 
 impl Default for Name {
@@ -30,12 +115,68 @@ impl Default for Name {
 impl Name {
     /// Creates a new [`Name`] from any string-like type.
     ///
-    /// The internal hash will be computed immediately.
+    /// The string will be shared with equal names if possible and the internal hash will be
+    /// computed immediately. A `&'static str` is never copied.
     pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        let name = match name.into() {
+            Cow::Borrowed(name) => SHARED_NAMES.get(name).map_or_else(
+                || NameStr::Static(STATIC_NAMES.intern_static(name).0),
+                NameStr::Shared,
+            ),
+            Cow::Owned(name) => match STATIC_NAMES.get(&name) {
+                Some(name) => NameStr::Static(name.0),
+                None => NameStr::Shared(SHARED_NAMES.intern(name)),
+            },
+        };
+        Self::from_name_str(name)
+    }
+
+    /// Creates a new [`Name`] from a `&'static str` in a `const` context.
+    ///
+    /// Unlike [`Name::new`], the string isn't shared with equal names created afterwards, which
+    /// only matters for comparisons by address: such names are still equal.
+    pub const fn from_static(name: &'static str) -> Self {
+        Name {
+            hash: hash_name(name),
+            name: NameStr::Static(name),
+        }
+    }
+
+    fn from_name_str(name: NameStr) -> Self {
+        let hash = match &name {
+            NameStr::Static(name) => hash_name(name),
+            NameStr::Shared(name) => hash_name(name),
+        };
+        Name { hash, name }
+    }
+
+    /// Creates a new [`Name`] from untrusted input, such as a name sent by a client.
+    ///
+    /// Unlike [`Name::new`], this rejects names longer than `max_len` characters and names
+    /// containing control characters, which could otherwise break logs or UI.
+    pub fn new_validated(
+        name: impl Into<Cow<'static, str>>,
+        max_len: usize,
+    ) -> Result<Self, NameError> {
         let name = name.into();
-        let mut name = Name { name, hash: 0 };
-        name.update_hash();
-        name
+        if let Some(c) = name.chars().find(|c| c.is_control()) {
+            return Err(NameError::ControlCharacter(c));
+        }
+        let len = name.chars().count();
+        if len > max_len {
+            return Err(NameError::TooLong { len, max_len });
+        }
+        Ok(Name::new(name))
+    }
+
+    /// Creates a new [`Name`] from untrusted input, removing anything [`Name::new_validated`]
+    /// would reject as a control character.
+    ///
+    /// Control characters are dropped and leading and trailing whitespace is trimmed. The length
+    /// is not limited.
+    pub fn sanitized(name: &str) -> Self {
+        let name: String = name.chars().filter(|c| !c.is_control()).collect();
+        Name::new(name.trim().to_owned())
     }
 
     /// Sets the entity's name.
@@ -48,43 +189,85 @@ impl Name {
 
     /// Updates the name of the entity in place.
     ///
-    /// This will allocate a new string if the name was previously
-    /// created from a borrow.
+    /// This will allocate a temporary string, which is shared with equal names afterwards.
     #[inline(always)]
     pub fn mutate<F: FnOnce(&mut String)>(&mut self, f: F) {
-        f(self.name.to_mut());
-        self.update_hash();
+        let mut name = self.as_str().to_owned();
+        f(&mut name);
+        *self = Name::new(name);
     }
 
     /// Gets the name of the entity as a `&str`.
     #[inline(always)]
     pub fn as_str(&self) -> &str {
-        &self.name
+        match &self.name {
+            NameStr::Static(name) => name,
+            NameStr::Shared(name) => name,
+        }
+    }
+}
+
+/// Hashes a name with FNV-1a, which unlike the default hasher can be computed in a `const` context.
+const fn hash_name(name: &str) -> u64 {
+    let bytes = name.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
     }
+    hash
+}
+
+/// A borrowed string used to look up [`Name`] keys without creating a [`Name`].
+pub(crate) struct NameLookup<'a> {
+    hash: u64,
+    name: &'a str,
+}
+
+impl<'a> NameLookup<'a> {
+    pub(crate) const fn new(name: &'a str) -> Self {
+        Self {
+            hash: hash_name(name),
+            name,
+        }
+    }
+}
+
+impl Hash for NameLookup<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Matches the `Hash` implementation of `Name`
+        self.hash.hash(state);
+    }
+}
 
-    fn update_hash(&mut self) {
-        let mut hasher = AHasher::default();
-        self.name.hash(&mut hasher);
-        self.hash = hasher.finish();
+impl Equivalent<Name> for NameLookup<'_> {
+    fn equivalent(&self, key: &Name) -> bool {
+        self.hash == key.hash && self.name == key.as_str()
     }
 }
 
 impl std::fmt::Display for Name {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        std::fmt::Display::fmt(&self.name, f)
+        std::fmt::Display::fmt(self.as_str(), f)
     }
 }
 
 impl std::fmt::Debug for Name {
     #[inline(always)]
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&self.name, f)
+        std::fmt::Debug::fmt(self.as_str(), f)
     }
 }
 
 /// Convenient query for giving a human friendly name to an entity.
 ///
+/// This is a [`QueryData`] borrowing the entity's [`Name`], so it needs the `entity_name` feature.
+/// To annotate engine code with a name that compiles to nothing when the feature is disabled,
+/// use [`OptionalName`](crate::OptionalName) instead.
+///
 /// ```
 /// # use ens_core::prelude::*;
 /// # use ens::prelude::*;
@@ -137,7 +320,7 @@ impl From<String> for Name {
 impl AsRef<str> for Name {
     #[inline(always)]
     fn as_ref(&self) -> &str {
-        &self.name
+        self.as_str()
     }
 }
 impl From<&Name> for String {
@@ -149,24 +332,21 @@ impl From<&Name> for String {
 impl From<Name> for String {
     #[inline(always)]
     fn from(val: Name) -> String {
-        val.name.into_owned()
+        val.as_str().to_owned()
     }
 }
 
 impl Hash for Name {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.name.hash(state);
+        // Equal names have the same string and therefore the same cached hash
+        self.hash.hash(state);
     }
 }
 
 impl PartialEq for Name {
     fn eq(&self, other: &Self) -> bool {
-        if self.hash != other.hash {
-            // Makes the common case of two strings not been equal very fast
-            return false;
-        }
-
-        self.name.eq(&other.name)
+        // Different hashes rule out equality without touching the strings
+        self.hash == other.hash && self.as_str() == other.as_str()
     }
 }
 
@@ -180,7 +360,10 @@ impl PartialOrd for Name {
 
 impl Ord for Name {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.name.cmp(&other.name)
+        if self == other {
+            return std::cmp::Ordering::Equal;
+        }
+        self.as_str().cmp(other.as_str())
     }
 }
 
@@ -188,6 +371,110 @@ impl Deref for Name {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        self.name.as_ref()
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_names_share_storage() {
+        let a = Name::new("Tree");
+        let b = Name::new(String::from("Tree"));
+        let c = Name::new("Rock");
+
+        assert!(std::ptr::eq(a.as_str(), b.as_str()));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn static_names_are_not_copied() {
+        const TREE: &str = "Static Tree";
+        assert!(std::ptr::eq(Name::new(TREE).as_str(), TREE));
+    }
+
+    #[test]
+    fn const_names_equal_runtime_names() {
+        const TREE: Name = Name::from_static("Tree");
+        assert_eq!(TREE, Name::new("Tree"));
+        assert_eq!(TREE.hash, Name::new(String::from("Tree")).hash);
+        assert_ne!(TREE, Name::new("Rock"));
+    }
+
+    #[test]
+    fn unused_owned_names_are_freed() {
+        let kept = Name::new(String::from("Kept"));
+        for i in 0..10_000 {
+            Name::new(format!("Client name {i}"));
+        }
+        // Only a bounded number of unused names are still waiting to be pruned
+        assert!(SHARED_NAMES.len() < 1_000);
+        assert!(std::ptr::eq(
+            kept.as_str(),
+            Name::new(String::from("Kept")).as_str()
+        ));
+    }
+
+    #[test]
+    fn mutate_reinterns() {
+        let mut name = Name::new("Tree");
+        name.mutate(|name| name.push_str("Stump"));
+
+        assert_eq!(name.as_str(), "TreeStump");
+        assert!(std::ptr::eq(name.as_str(), Name::new("TreeStump").as_str()));
+    }
+
+    #[test]
+    fn mutation_updates_cached_hash() {
+        let mut name = Name::new("Tree");
+        let tree_hash = name.hash;
+
+        name.set("Rock");
+        assert_eq!(name.hash, Name::new("Rock").hash);
+        assert_ne!(name.hash, tree_hash);
+
+        name.mutate(|name| *name = "Tree".to_owned());
+        assert_eq!(name.hash, tree_hash);
+
+        let names = ["Tree", "Rock", "tree", "", "Tree "].map(Name::new);
+        for a in &names {
+            for b in &names {
+                assert_eq!(a == b, a.as_str() == b.as_str());
+                assert_eq!(a.cmp(b), a.as_str().cmp(b.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn validated_rejects_overlong_names() {
+        assert_eq!(Name::new_validated("Tree", 4).unwrap().as_str(), "Tree");
+        assert_eq!(
+            Name::new_validated("Trees", 4),
+            Err(NameError::TooLong { len: 5, max_len: 4 })
+        );
+        // Length is counted in characters, not bytes
+        assert!(Name::new_validated("Ünïcödé", 7).is_ok());
+    }
+
+    #[test]
+    fn validated_rejects_control_characters() {
+        assert_eq!(
+            Name::new_validated("Tree\n", 16),
+            Err(NameError::ControlCharacter('\n'))
+        );
+        assert_eq!(
+            Name::new_validated("\u{1b}[31mTree", 16),
+            Err(NameError::ControlCharacter('\u{1b}'))
+        );
+    }
+
+    #[test]
+    fn sanitized_strips_control_characters() {
+        assert_eq!(Name::sanitized(" Tr\0ee\r\n").as_str(), "Tree");
+        assert_eq!(Name::sanitized("\u{1b}[31mTree").as_str(), "[31mTree");
+        assert_eq!(Name::sanitized("Tree"), Name::new("Tree"));
     }
 }
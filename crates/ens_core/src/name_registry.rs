@@ -0,0 +1,119 @@
+use crate::{name::NameLookup, Name};
+use ens::prelude::*;
+use ens_app::{App, Plugin, PostUpdate};
+use ens_utils::HashMap;
+use smallvec::SmallVec;
+
+/// Adds a [`NameRegistry`] that is kept up to date in [`PostUpdate`], inside
+/// [`NameRegistrySystem`].
+pub struct NameRegistryPlugin;
+
+impl Plugin for NameRegistryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NameRegistry>()
+            .add_systems(PostUpdate, update_name_registry.in_set(NameRegistrySystem));
+    }
+}
+
+/// Updates the [`NameRegistry`]. Systems in [`PostUpdate`] reading the registry should run after
+/// this to see the names added during the same frame.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct NameRegistrySystem;
+
+/// Looks up entities by their [`Name`] without scanning every entity.
+///
+/// This is meant for tooling and debugging, like console commands or editors. Names are not
+/// unique, so a name may resolve to several entities, in the order their names were set.
+///
+/// The registry is updated once per frame by [`NameRegistryPlugin`], so names added, changed or
+/// removed since the last [`PostUpdate`] are not reflected yet.
+#[derive(Resource, Default, Debug)]
+pub struct NameRegistry {
+    entities: HashMap<Name, SmallVec<[Entity; 1]>>,
+    names: HashMap<Entity, Name>,
+}
+
+impl NameRegistry {
+    /// Returns the entities named `name`, or an empty slice if there are none.
+    pub fn get(&self, name: &str) -> &[Entity] {
+        self.entities
+            .get(&NameLookup::new(name))
+            .map_or(&[], |entities| entities.as_slice())
+    }
+
+    /// Returns the number of distinct names in the registry.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no entity in the registry has a name.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn insert(&mut self, entity: Entity, name: Name) {
+        self.remove(entity);
+        self.entities.entry(name.clone()).or_default().push(entity);
+        self.names.insert(entity, name);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        let Some(name) = self.names.remove(&entity) else {
+            return;
+        };
+        if let Some(entities) = self.entities.get_mut(&name) {
+            entities.retain(|e| *e != entity);
+            if entities.is_empty() {
+                self.entities.remove(&name);
+            }
+        }
+    }
+}
+
+/// The system used to update the [`NameRegistry`] from added, changed and removed [`Name`]s.
+pub fn update_name_registry(
+    mut registry: ResMut<NameRegistry>,
+    changed: Query<(Entity, &Name), Changed<Name>>,
+    mut removed: RemovedComponents<Name>,
+) {
+    for entity in removed.read() {
+        registry.remove(entity);
+    }
+    for (entity, name) in &changed {
+        registry.insert(entity, name.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_shared_and_unique_names() {
+        let mut app = App::new();
+        app.add_plugins(NameRegistryPlugin);
+
+        let tree_a = app.world_mut().spawn(Name::new("Tree")).id();
+        let tree_b = app.world_mut().spawn(Name::new("Tree")).id();
+        let rock = app.world_mut().spawn(Name::new("Rock")).id();
+        let statue = app.world_mut().spawn(Name::from_static("Statue")).id();
+        app.update();
+
+        let registry = app.world().resource::<NameRegistry>();
+        assert_eq!(registry.get("Tree"), [tree_a, tree_b]);
+        assert_eq!(registry.get("Rock"), [rock]);
+        assert_eq!(registry.get("Statue"), [statue]);
+        assert!(registry.get("Never used as a name").is_empty());
+
+        app.world_mut().despawn(tree_a);
+        app.world_mut()
+            .entity_mut(rock)
+            .insert(Name::new("Boulder"));
+        app.update();
+
+        let registry = app.world().resource::<NameRegistry>();
+        assert_eq!(registry.get("Tree"), [tree_b]);
+        assert!(registry.get("Rock").is_empty());
+        assert_eq!(registry.get("Boulder"), [rock]);
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "entity_name")]
+use crate::Name;
+
+/// An optional [`Name`](crate::Name) used to annotate engine code, such as systems or resources,
+/// in logs and errors.
+///
+/// Unlike [`Name`](crate::Name), this is always available. When the `entity_name` feature is
+/// disabled it is a zero-sized type and the name passed to [`OptionalName::new`] is discarded,
+/// so library code can use it without forcing the feature on.
+///
+/// This is not `DebugName`: that type is a query borrowing an entity's [`Name`](crate::Name), which
+/// existing systems rely on and which can't exist without the feature.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OptionalName {
+    #[cfg(feature = "entity_name")]
+    name: Option<Name>,
+}
+
+impl OptionalName {
+    /// Creates a new [`OptionalName`]. The name is discarded if `entity_name` is disabled.
+    #[cfg_attr(not(feature = "entity_name"), allow(unused_variables))]
+    #[inline(always)]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            #[cfg(feature = "entity_name")]
+            name: Some(Name::from_static(name)),
+        }
+    }
+
+    /// Creates an [`OptionalName`] without a name.
+    #[inline(always)]
+    pub const fn none() -> Self {
+        Self {
+            #[cfg(feature = "entity_name")]
+            name: None,
+        }
+    }
+
+    /// Returns the name, if there is one.
+    #[cfg(feature = "entity_name")]
+    #[inline(always)]
+    pub fn name(&self) -> Option<&Name> {
+        self.name.as_ref()
+    }
+
+    /// Returns the name as a `&str`, or [`None`] if there is none or `entity_name` is disabled.
+    #[inline(always)]
+    pub fn as_str(&self) -> Option<&str> {
+        #[cfg(feature = "entity_name")]
+        {
+            self.name.as_ref().map(Name::as_str)
+        }
+        #[cfg(not(feature = "entity_name"))]
+        {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "entity_name")]
+impl From<Name> for OptionalName {
+    #[inline(always)]
+    fn from(name: Name) -> Self {
+        Self { name: Some(name) }
+    }
+}
+
+impl std::fmt::Display for OptionalName {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        #[cfg(feature = "entity_name")]
+        match &self.name {
+            Some(name) => std::fmt::Display::fmt(name, f),
+            None => f.write_str("<unnamed>"),
+        }
+        #[cfg(not(feature = "entity_name"))]
+        f.write_str("<name disabled>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TREE: OptionalName = OptionalName::new("Tree");
+
+    #[cfg(feature = "entity_name")]
+    #[test]
+    fn keeps_name() {
+        assert_eq!(TREE.as_str(), Some("Tree"));
+        assert_eq!(TREE.name(), Some(&Name::new("Tree")));
+        assert_eq!(TREE.to_string(), "Tree");
+        assert_eq!(OptionalName::none().to_string(), "<unnamed>");
+    }
+
+    #[cfg(not(feature = "entity_name"))]
+    #[test]
+    fn zero_sized_without_entity_name() {
+        assert_eq!(std::mem::size_of::<OptionalName>(), 0);
+        assert_eq!(TREE.as_str(), None);
+        assert_eq!(TREE.to_string(), "<name disabled>");
+        assert_eq!(OptionalName::none().to_string(), "<name disabled>");
+    }
+}
@@ -0,0 +1,107 @@
+use crate::Name;
+use ens::prelude::*;
+use ens::system::EntityCommands;
+use ens_utils::HashMap;
+
+/// A function that fills a freshly spawned entity with components.
+pub type PrefabFn = Box<dyn Fn(&mut EntityCommands) + Send + Sync>;
+
+/// A registry of named spawn functions, for spawning entities from data such as level files.
+///
+/// Prefabs are registered once, typically while building the app, and spawned by [`Name`] with
+/// [`PrefabCommandsExt::spawn_prefab`].
+#[derive(Resource, Default)]
+pub struct PrefabRegistry {
+    prefabs: HashMap<Name, PrefabFn>,
+}
+
+impl PrefabRegistry {
+    /// Registers `f` as the prefab called `name`, replacing any prefab with the same name.
+    pub fn register(
+        &mut self,
+        name: impl Into<Name>,
+        f: impl Fn(&mut EntityCommands) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.prefabs.insert(name.into(), Box::new(f));
+        self
+    }
+
+    /// Returns `true` if a prefab called `name` is registered.
+    pub fn contains(&self, name: &Name) -> bool {
+        self.prefabs.contains_key(name)
+    }
+
+    /// Returns the spawn function of the prefab called `name`.
+    pub fn get(&self, name: &Name) -> Option<&PrefabFn> {
+        self.prefabs.get(name)
+    }
+}
+
+/// An extension trait for [`Commands`] that spawns prefabs from a [`PrefabRegistry`].
+///
+/// [`Commands`] cannot read resources, so the registry is passed in, usually as a
+/// `Res<PrefabRegistry>` system parameter.
+pub trait PrefabCommandsExt {
+    /// Spawns a new entity, applies the prefab called `name` to it and returns its
+    /// [`EntityCommands`] so more components can be added.
+    ///
+    /// Returns `None` and logs a warning, without spawning anything, if no such prefab is
+    /// registered.
+    fn spawn_prefab(
+        &mut self,
+        registry: &PrefabRegistry,
+        name: impl Into<Name>,
+    ) -> Option<EntityCommands<'_>>;
+}
+
+impl PrefabCommandsExt for Commands<'_, '_> {
+    fn spawn_prefab(
+        &mut self,
+        registry: &PrefabRegistry,
+        name: impl Into<Name>,
+    ) -> Option<EntityCommands<'_>> {
+        let name = name.into();
+        let Some(prefab) = registry.get(&name) else {
+            log::warn!("no prefab named {name:?} is registered");
+            return None;
+        };
+
+        let mut entity = self.spawn_empty();
+        prefab(&mut entity);
+        Some(entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ens::system::RunSystemOnce;
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Health(u32);
+
+    #[derive(Component)]
+    struct Hostile;
+
+    #[test]
+    fn spawn_registered_prefab() {
+        let mut world = World::new();
+        let mut registry = PrefabRegistry::default();
+        registry.register("Goblin", |entity| {
+            entity.insert((Health(7), Hostile));
+        });
+        world.insert_resource(registry);
+
+        world.run_system_once(|mut commands: Commands, registry: Res<PrefabRegistry>| {
+            let goblin = commands.spawn_prefab(&registry, "Goblin");
+            goblin.unwrap().insert(Name::new("Grub"));
+            assert!(commands.spawn_prefab(&registry, "Dragon").is_none());
+        });
+
+        let mut query = world.query_filtered::<(&Health, &Name), With<Hostile>>();
+        let (health, name) = query.single(&world);
+        assert_eq!(health, &Health(7));
+        assert_eq!(name.as_str(), "Grub");
+        assert_eq!(world.entities().len(), 1);
+    }
+}
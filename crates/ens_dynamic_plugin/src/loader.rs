@@ -1,28 +1,61 @@
 use libloading::{Library, Symbol};
-use std::ffi::OsStr;
+use std::{
+    ffi::OsStr,
+    panic::{self, AssertUnwindSafe},
+};
 use thiserror::Error;
 
-use ens_app::{App, CreatePlugin, Plugin};
+use ens_app::{App, CreatePlugin, Plugin, PluginAbiVersion, DYNAMIC_PLUGIN_ABI_VERSION};
+
+/// The symbol a dynamic plugin exports to create its [`Plugin`].
+const CREATE_PLUGIN_SYMBOL: &str = "_ens_create_plugin";
+/// The symbol a dynamic plugin exports to report its [`DYNAMIC_PLUGIN_ABI_VERSION`].
+const ABI_VERSION_SYMBOL: &str = "_ens_plugin_abi_version";
 
 /// Errors that can occur when loading a dynamic plugin
 #[derive(Debug, Error)]
 pub enum DynamicPluginLoadError {
-    /// An error occurred when loading a dynamic library.
-    #[error("cannot load library for dynamic plugin: {0}")]
-    Library(#[source] libloading::Error),
-    /// An error occurred when loading a library without a valid Bevy plugin.
-    #[error("dynamic library does not contain a valid Bevy dynamic plugin")]
-    Plugin(#[source] libloading::Error),
+    /// The dynamic library could not be opened, e.g. because it does not exist or is not a valid
+    /// library for this platform.
+    #[error("cannot open library for dynamic plugin: {0}")]
+    LibraryOpen(#[source] libloading::Error),
+    /// The library does not export a symbol every dynamic plugin has, so it is most likely not a
+    /// dynamic plugin.
+    #[error("dynamic library does not export `{0}`, is it deriving `DynamicPlugin`?")]
+    MissingSymbol(String),
+    /// The plugin was built against an `ens_app` with a different
+    /// [`DYNAMIC_PLUGIN_ABI_VERSION`], and must be rebuilt.
+    #[error(
+        "dynamic plugin was built for ABI version {found}, but version {expected} is required"
+    )]
+    AbiMismatch {
+        /// The ABI version of this program.
+        expected: u32,
+        /// The ABI version the plugin was built with.
+        found: u32,
+    },
+    /// The plugin panicked in [`Plugin::build`], with the given message.
+    #[error("dynamic plugin panicked during build: {0}")]
+    PanicDuringBuild(String),
 }
 
 /// Dynamically links a plugin at the given path. The plugin must export a function with the
-/// [`CreatePlugin`] signature named `_ens_create_plugin`.
+/// [`CreatePlugin`] signature named `_ens_create_plugin` and a function with the
+/// [`PluginAbiVersion`] signature named `_ens_plugin_abi_version`.
+///
+/// # Errors
+///
+/// Returns [`DynamicPluginLoadError::LibraryOpen`] if the library cannot be opened,
+/// [`DynamicPluginLoadError::MissingSymbol`] if either function is not exported and
+/// [`DynamicPluginLoadError::AbiMismatch`] if the plugin was built for another
+/// [`DYNAMIC_PLUGIN_ABI_VERSION`].
 ///
 /// # Safety
 ///
 /// The specified plugin must be linked against the exact same `libens.so` as this program.
-/// In addition the `_ens_create_plugin` symbol must not be manually created, but instead created
-/// by deriving `DynamicPlugin` on a unit struct implementing [`Plugin`].
+/// In addition the `_ens_create_plugin` and `_ens_plugin_abi_version` symbols must not be
+/// manually created, but instead created by deriving `DynamicPlugin` on a unit struct
+/// implementing [`Plugin`].
 ///
 /// Dynamically loading plugins is orchestrated through dynamic linking. When linking against
 /// foreign code, initialization routines may be run (as well as termination routines when the
@@ -32,15 +65,30 @@ pub unsafe fn dynamically_load_plugin<P: AsRef<OsStr>>(
     path: P,
 ) -> Result<(Library, Box<dyn Plugin>), DynamicPluginLoadError> {
     // SAFETY: Caller must follow the safety requirements of Library::new.
-    let lib = unsafe { Library::new(path).map_err(DynamicPluginLoadError::Library)? };
+    let lib = unsafe { Library::new(path).map_err(DynamicPluginLoadError::LibraryOpen)? };
 
     // SAFETY: Loaded plugins must not specify `_ens_create_plugin` symbol manually, it is
     // instead automatically generated through `DynamicPlugin`.
     let func: Symbol<CreatePlugin> = unsafe {
-        lib.get(b"_ens_create_plugin")
-            .map_err(DynamicPluginLoadError::Plugin)?
+        lib.get(CREATE_PLUGIN_SYMBOL.as_bytes())
+            .map_err(|_| DynamicPluginLoadError::MissingSymbol(CREATE_PLUGIN_SYMBOL.into()))?
+    };
+
+    // SAFETY: Same as above, `_ens_plugin_abi_version` is generated through `DynamicPlugin`.
+    let abi_version: Symbol<PluginAbiVersion> = unsafe {
+        lib.get(ABI_VERSION_SYMBOL.as_bytes())
+            .map_err(|_| DynamicPluginLoadError::MissingSymbol(ABI_VERSION_SYMBOL.into()))?
     };
 
+    // SAFETY: `abi_version` is automatically generated and only returns a constant.
+    let found = unsafe { abi_version() };
+    if found != DYNAMIC_PLUGIN_ABI_VERSION {
+        return Err(DynamicPluginLoadError::AbiMismatch {
+            expected: DYNAMIC_PLUGIN_ABI_VERSION,
+            found,
+        });
+    }
+
     // SAFETY: `func` is automatically generated and is guaranteed to return a pointer created using
     // `Box::into_raw`.
     let plugin = unsafe { Box::from_raw(func()) };
@@ -54,18 +102,66 @@ pub trait DynamicPluginExt {
     ///
     /// For more details, see [`dynamically_load_plugin`].
     ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`dynamically_load_plugin`], and
+    /// [`DynamicPluginLoadError::PanicDuringBuild`] if [`Plugin::build`] panics. In that case the
+    /// app may have been partially modified by the plugin.
+    ///
     /// # Safety
     ///
     /// See [`dynamically_load_plugin`]'s safety section.
-    unsafe fn load_plugin<P: AsRef<OsStr>>(&mut self, path: P) -> &mut Self;
+    unsafe fn load_plugin<P: AsRef<OsStr>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginLoadError>;
 }
 
 impl DynamicPluginExt for App {
-    unsafe fn load_plugin<P: AsRef<OsStr>>(&mut self, path: P) -> &mut Self {
-        // SAFETY: Follows the same safety requirements as `load_plugin`.
-        let (lib, plugin) = unsafe { load_plugin(path).unwrap() };
+    unsafe fn load_plugin<P: AsRef<OsStr>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginLoadError> {
+        // SAFETY: Follows the same safety requirements as `dynamically_load_plugin`.
+        let (lib, plugin) = unsafe { dynamically_load_plugin(path)? };
         std::mem::forget(lib); // Ensure that the library is not automatically unloaded
-        plugin.build(self);
-        self
+
+        panic::catch_unwind(AssertUnwindSafe(|| plugin.build(self))).map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            DynamicPluginLoadError::PanicDuringBuild(message)
+        })?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_failure() {
+        // SAFETY: The library does not exist, so nothing is loaded.
+        let result = unsafe { dynamically_load_plugin("./this_plugin_does_not_exist.so") };
+        assert!(matches!(
+            result,
+            Err(DynamicPluginLoadError::LibraryOpen(_))
+        ));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn missing_symbol() {
+        // SAFETY: The C library is already loaded into every process on Linux.
+        let result = unsafe { dynamically_load_plugin("libc.so.6") };
+        match result {
+            Err(DynamicPluginLoadError::MissingSymbol(symbol)) => {
+                assert_eq!(symbol, CREATE_PLUGIN_SYMBOL);
+            }
+            other => panic!("expected a missing symbol, got {:?}", other.map(|_| ())),
+        }
     }
 }
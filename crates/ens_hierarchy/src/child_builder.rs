@@ -1,4 +1,6 @@
-use crate::{Children, HierarchyEvent, Parent};
+#[cfg(feature = "events")]
+use crate::HierarchyEvent;
+use crate::{Children, Parent};
 #[cfg(feature = "events")]
 use ens::event::Events;
 use ens::{
@@ -77,6 +79,7 @@ fn update_old_parent(world: &mut World, child: Entity, parent: Entity) {
         }
         remove_from_children(world, previous_parent, child);
 
+        #[cfg(feature = "events")]
         push_events(
             world,
             [HierarchyEvent::ChildMoved {
@@ -86,6 +89,7 @@ fn update_old_parent(world: &mut World, child: Entity, parent: Entity) {
             }],
         );
     } else {
+        #[cfg(feature = "events")]
         push_events(world, [HierarchyEvent::ChildAdded { child, parent }]);
     }
 }
@@ -120,31 +124,31 @@ fn update_old_parents(world: &mut World, parent: Entity, children: &[Entity]) {
             events.push(HierarchyEvent::ChildAdded { child, parent });
         }
     }
+    #[cfg(feature = "events")]
     push_events(world, events);
 }
 
 /// Removes entities in `children` from `parent`'s [`Children`], removing the component if it ends up empty.
 /// Also removes [`Parent`] component from `children`.
 fn remove_children(parent: Entity, children: &[Entity], world: &mut World) {
-    #[cfg(feature = "events")]
-    {
-        let mut events: SmallVec<[HierarchyEvent; 8]> = SmallVec::new();
-        if let Some(parent_children) = world.get::<Children>(parent) {
-            for &child in children {
-                if parent_children.contains(&child) {
-                    events.push(HierarchyEvent::ChildRemoved { child, parent });
-                }
-            }
-        } else {
-            return;
-        }
-        for event in &events {
-            if let &HierarchyEvent::ChildRemoved { child, .. } = event {
-                world.entity_mut(child).remove::<Parent>();
-            }
-        }
-        push_events(world, events);
+    let Some(parent_children) = world.get::<Children>(parent) else {
+        return;
+    };
+    let removed: SmallVec<[Entity; 8]> = children
+        .iter()
+        .copied()
+        .filter(|child| parent_children.contains(child))
+        .collect();
+    for &child in &removed {
+        world.entity_mut(child).remove::<Parent>();
     }
+    #[cfg(feature = "events")]
+    push_events(
+        world,
+        removed
+            .into_iter()
+            .map(|child| HierarchyEvent::ChildRemoved { child, parent }),
+    );
 
     let mut parent = world.entity_mut(parent);
     if let Some(mut parent_children) = parent.get_mut::<Children>() {
@@ -493,6 +497,7 @@ impl<'w> WorldChildBuilder<'w> {
     pub fn spawn(&mut self, bundle: impl Bundle + Send + Sync + 'static) -> EntityWorldMut<'_> {
         let entity = self.world.spawn((bundle, Parent(self.parent))).id();
         push_child_unchecked(self.world, self.parent, entity);
+        #[cfg(feature = "events")]
         push_events(
             self.world,
             [HierarchyEvent::ChildAdded {
@@ -508,6 +513,7 @@ impl<'w> WorldChildBuilder<'w> {
     pub fn spawn_empty(&mut self) -> EntityWorldMut<'_> {
         let entity = self.world.spawn(Parent(self.parent)).id();
         push_child_unchecked(self.world, self.parent, entity);
+        #[cfg(feature = "events")]
         push_events(
             self.world,
             [HierarchyEvent::ChildAdded {
@@ -679,6 +685,7 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
         if let Some(parent) = self.take::<Parent>().map(|p| p.get()) {
             self.world_scope(|world| {
                 remove_from_children(world, parent, child);
+                #[cfg(feature = "events")]
                 push_events(world, [HierarchyEvent::ChildRemoved { child, parent }]);
             });
         }
@@ -710,8 +717,8 @@ mod tests {
     use ens::{
         component::Component,
         entity::Entity,
-        event::Events,
-        system::Commands,
+        event::{EventReader, Events},
+        system::{Commands, SystemState},
         world::{CommandQueue, World},
     };
 
@@ -1229,6 +1236,40 @@ mod tests {
         assert!(world.get::<Children>(parent2).is_none());
     }
 
+    #[test]
+    fn hierarchy_events_are_sent_by_commands() {
+        let mut world = World::new();
+        world.insert_resource(Events::<HierarchyEvent>::default());
+        let mut reader = SystemState::<EventReader<HierarchyEvent>>::new(&mut world);
+
+        let [a, b, child] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(a).push_children(&[child]);
+            commands.entity(b).push_children(&[child]);
+            commands.entity(b).remove_children(&[child]);
+        }
+        assert_eq!(reader.get_mut(&mut world).read().count(), 0);
+
+        queue.apply(&mut world);
+        let events: Vec<_> = reader.get_mut(&mut world).read().cloned().collect();
+        assert_eq!(
+            events,
+            [
+                ChildAdded { child, parent: a },
+                ChildMoved {
+                    child,
+                    previous_parent: a,
+                    new_parent: b,
+                },
+                ChildRemoved { child, parent: b },
+            ]
+        );
+        assert_parent(&world, child, None);
+    }
+
     #[test]
     fn regression_push_children_same_archetype() {
         let mut world = World::new();
@@ -1250,4 +1291,30 @@ mod tests {
         let children = query.get(&world, parent).unwrap();
         assert_eq!(**children, [child]);
     }
+
+    #[test]
+    fn reorder_children() {
+        let mut world = World::new();
+        let [a, b, c] = [C(3), C(1), C(2)].map(|key| world.spawn(key).id());
+        let parent = world.spawn_empty().push_children(&[a, b, c]).id();
+
+        world.get_mut::<Children>(parent).unwrap().swap(0, 2);
+        assert_children(&world, parent, Some(&[c, b, a]));
+
+        let keys: Vec<_> = [a, b, c]
+            .map(|child| (child, world.get::<C>(child).unwrap().0))
+            .into();
+        world
+            .get_mut::<Children>(parent)
+            .unwrap()
+            .sort_by_key(|child| keys.iter().find(|(e, _)| e == child).unwrap().1);
+        assert_children(&world, parent, Some(&[b, c, a]));
+
+        // Inserting an existing child moves it instead of duplicating it.
+        world.entity_mut(parent).insert_children(0, &[a]);
+        assert_children(&world, parent, Some(&[a, b, c]));
+        for child in [a, b, c] {
+            assert_parent(&world, child, Some(parent));
+        }
+    }
 }
@@ -115,7 +115,10 @@ mod tests {
     };
 
     use super::DespawnRecursiveExt;
-    use crate::{child_builder::BuildChildren, components::Children};
+    use crate::{
+        child_builder::{BuildChildren, BuildWorldChildren},
+        components::Children,
+    };
 
     #[derive(Component, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Debug)]
     struct Idx(u32);
@@ -220,6 +223,24 @@ mod tests {
         assert!(world.get_entity(child).is_none());
     }
 
+    #[test]
+    fn despawn_descendants_keeps_root() {
+        let mut world = World::default();
+
+        let [root, child, grandchild, bystander] =
+            std::array::from_fn(|i| world.spawn(Idx(i as u32)).id());
+        world.entity_mut(root).push_children(&[child]);
+        world.entity_mut(child).push_children(&[grandchild]);
+
+        world.entity_mut(root).despawn_descendants();
+
+        assert!(world.get_entity(root).is_some());
+        assert!(world.get::<Children>(root).is_none());
+        assert!(world.get_entity(child).is_none());
+        assert!(world.get_entity(grandchild).is_none());
+        assert!(world.get_entity(bystander).is_some());
+    }
+
     #[test]
     fn spawn_children_after_despawn_descendants() {
         let mut world = World::default();
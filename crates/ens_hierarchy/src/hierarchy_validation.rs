@@ -0,0 +1,71 @@
+use crate::{Children, Parent};
+use ens::{entity::Entities, prelude::*};
+
+/// The number of hierarchy inconsistencies found by [`validate_parent_has_reverse_link`].
+///
+/// This resource is added by [`HierarchyPlugin::with_validation`](crate::HierarchyPlugin::with_validation).
+/// The count is never reset, so it can be used to detect that an issue happened at all.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HierarchyIssueCount(pub usize);
+
+/// System to print a warning for each [`Entity`] whose [`Parent`] is despawned or doesn't list it
+/// in its [`Children`].
+///
+/// The hierarchy is kept consistent by the methods of this crate, so such an entity means that
+/// [`Parent`] or [`Children`] were mutated manually, or that an entity was despawned without
+/// [`despawn_recursive`](crate::DespawnRecursiveExt::despawn_recursive).
+///
+/// Every inconsistency is counted in [`HierarchyIssueCount`], and reported again each run until
+/// it is fixed.
+pub fn validate_parent_has_reverse_link(
+    parent_query: Query<(Entity, &Parent)>,
+    children_query: Query<&Children>,
+    entities: &Entities,
+    mut issues: ResMut<HierarchyIssueCount>,
+) {
+    for (entity, parent) in &parent_query {
+        let parent = parent.get();
+        if !entities.contains(parent) {
+            issues.0 += 1;
+            log::warn!("{entity:?} has a despawned parent {parent:?}.");
+        } else if !children_query
+            .get(parent)
+            .is_ok_and(|children| children.contains(&entity))
+        {
+            issues.0 += 1;
+            log::warn!("{entity:?} has parent {parent:?}, but is not one of its children.");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ens::{system::RunSystemOnce, world::World};
+
+    use super::{validate_parent_has_reverse_link, HierarchyIssueCount};
+    use crate::{BuildWorldChildren, Parent};
+
+    #[test]
+    fn reports_inconsistent_parents() {
+        let mut world = World::default();
+        world.init_resource::<HierarchyIssueCount>();
+
+        let parent = world.spawn_empty().id();
+        world.spawn_empty().set_parent(parent);
+        world.run_system_once(validate_parent_has_reverse_link);
+        assert_eq!(world.resource::<HierarchyIssueCount>().0, 0);
+
+        // A parent that doesn't know about its child.
+        world.spawn(Parent(parent));
+        world.run_system_once(validate_parent_has_reverse_link);
+        assert_eq!(world.resource::<HierarchyIssueCount>().0, 1);
+
+        // A parent that was despawned, leaving its children dangling. The previous issue is
+        // reported again.
+        let despawned = world.spawn_empty().id();
+        world.despawn(despawned);
+        world.spawn(Parent(despawned));
+        world.run_system_once(validate_parent_has_reverse_link);
+        assert_eq!(world.resource::<HierarchyIssueCount>().0, 3);
+    }
+}
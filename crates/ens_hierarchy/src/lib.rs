@@ -62,6 +62,9 @@ pub use events::*;
 mod valid_parent_check_plugin;
 pub use valid_parent_check_plugin::*;
 
+mod hierarchy_validation;
+pub use hierarchy_validation::*;
+
 mod query_extension;
 pub use query_extension::*;
 
@@ -84,11 +87,30 @@ use ens_app::prelude::*;
 ///
 /// [crate-level documentation]: crate
 #[derive(Default)]
-pub struct HierarchyPlugin;
+pub struct HierarchyPlugin {
+    validate: bool,
+}
+
+impl HierarchyPlugin {
+    /// Also runs [`validate_parent_has_reverse_link`] in [`PostUpdate`], which warns about
+    /// entities whose [`Parent`] is despawned or doesn't list them in its [`Children`].
+    ///
+    /// This is a debugging aid that queries every entity with a [`Parent`] each frame, so it is
+    /// disabled by default.
+    pub fn with_validation(mut self) -> Self {
+        self.validate = true;
+        self
+    }
+}
 
 impl Plugin for HierarchyPlugin {
     fn build(&self, app: &mut App) {
         #[cfg(feature = "events")]
         app.add_event::<HierarchyEvent>();
+
+        if self.validate {
+            app.init_resource::<HierarchyIssueCount>()
+                .add_systems(PostUpdate, validate_parent_has_reverse_link);
+        }
     }
 }
@@ -55,6 +55,27 @@ pub trait HierarchyQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
     fn iter_ancestors(&'w self, entity: Entity) -> AncestorIter<'w, 's, D, F>
     where
         D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>;
+
+    /// Returns the number of ancestors of `entity`, which is 0 for a root.
+    ///
+    /// Can only be called on a [`Query`] of [`Parent`] (i.e. `Query<&Parent>`).
+    ///
+    /// If the [`Parent`] links form a cycle, which can only happen if they were mutated
+    /// manually, a warning is logged and [`usize::MAX`] is returned.
+    fn depth(&'w self, entity: Entity) -> usize
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>;
+
+    /// Returns the root of the hierarchy `entity` is part of, which is `entity` itself if it has
+    /// no [`Parent`].
+    ///
+    /// Can only be called on a [`Query`] of [`Parent`] (i.e. `Query<&Parent>`).
+    ///
+    /// If the [`Parent`] links form a cycle, which can only happen if they were mutated
+    /// manually, a warning is logged and `entity` is returned.
+    fn root_ancestor(&'w self, entity: Entity) -> Entity
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>;
 }
 
 impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Query<'w, 's, D, F> {
@@ -71,6 +92,52 @@ impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Q
     {
         AncestorIter::new(self, entity)
     }
+
+    fn depth(&'w self, entity: Entity) -> usize
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
+    {
+        walk_to_root(self, entity).map_or(usize::MAX, |(depth, _)| depth)
+    }
+
+    fn root_ancestor(&'w self, entity: Entity) -> Entity
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
+    {
+        walk_to_root(self, entity).map_or(entity, |(_, root)| root)
+    }
+}
+
+/// Follows the [`Parent`] links of `entity` to the root, returning the number of steps taken and
+/// the root, or [`None`] if the links form a cycle.
+///
+/// Cycles are detected with Brent's algorithm, so this takes no more steps than the length of
+/// the walk and doesn't allocate.
+fn walk_to_root<'w, 's, D: QueryData, F: QueryFilter>(
+    parent_query: &'w Query<'w, 's, D, F>,
+    entity: Entity,
+) -> Option<(usize, Entity)>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
+{
+    let mut depth = 0;
+    let mut root = entity;
+    let mut checkpoint = entity;
+    let mut next_checkpoint = 1;
+    for ancestor in parent_query.iter_ancestors(entity) {
+        if ancestor == checkpoint {
+            log::warn!("{entity:?} has a cycle in its Parent links through {ancestor:?}.");
+            return None;
+        }
+
+        depth += 1;
+        root = ancestor;
+        if depth == next_checkpoint {
+            checkpoint = ancestor;
+            next_checkpoint *= 2;
+        }
+    }
+    Some((depth, root))
 }
 
 /// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
@@ -201,4 +268,42 @@ mod tests {
 
         assert_eq!([&A(1), &A(0)], result.as_slice());
     }
+
+    #[test]
+    fn depth_and_root_ancestor() {
+        let world = &mut World::new();
+
+        let [a, b, c, d] = std::array::from_fn(|i| world.spawn(A(i)).id());
+
+        world.entity_mut(a).push_children(&[b]);
+        world.entity_mut(b).push_children(&[c]);
+        world.entity_mut(c).push_children(&[d]);
+
+        let mut system_state = SystemState::<Query<&Parent>>::new(world);
+        let parent_query = system_state.get(world);
+
+        assert_eq!(parent_query.depth(a), 0);
+        assert_eq!(parent_query.depth(d), 3);
+        assert_eq!(parent_query.root_ancestor(a), a);
+        assert_eq!(parent_query.root_ancestor(d), a);
+    }
+
+    #[test]
+    fn depth_with_cycle() {
+        let world = &mut World::new();
+
+        let [a, b, c, d] = std::array::from_fn(|i| world.spawn(A(i)).id());
+
+        // d -> c -> b -> a -> c
+        world.entity_mut(d).insert(Parent(c));
+        world.entity_mut(c).insert(Parent(b));
+        world.entity_mut(b).insert(Parent(a));
+        world.entity_mut(a).insert(Parent(c));
+
+        let mut system_state = SystemState::<Query<&Parent>>::new(world);
+        let parent_query = system_state.get(world);
+
+        assert_eq!(parent_query.depth(d), usize::MAX);
+        assert_eq!(parent_query.root_ancestor(d), d);
+    }
 }
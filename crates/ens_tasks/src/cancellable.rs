@@ -0,0 +1,186 @@
+use std::{
+    fmt,
+    future::Future,
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+/// How long [`block_on_cancellable`] sleeps between checks of the cancel flag when the future is
+/// not woken.
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Blocks the current thread on `future` like [`block_on`](crate::block_on), but gives up and
+/// returns [`None`] as soon as `cancel` is set to `true`.
+///
+/// The flag is checked before every poll, and at least every millisecond while the future is
+/// pending, so a shutdown does not hang on a future that never completes. When cancelled, the
+/// future is dropped without being polled again.
+///
+/// ```
+/// use ens_tasks::{block_on_cancellable, futures_lite::future};
+/// use std::sync::atomic::AtomicBool;
+///
+/// let cancel = AtomicBool::new(false);
+/// assert_eq!(block_on_cancellable(async { 42 }, &cancel), Some(42));
+///
+/// let cancel = AtomicBool::new(true);
+/// assert_eq!(block_on_cancellable(future::pending::<()>(), &cancel), None);
+/// ```
+pub fn block_on_cancellable<T>(future: impl Future<Output = T>, cancel: &AtomicBool) -> Option<T> {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        if cancel.load(Ordering::Acquire) {
+            return None;
+        }
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return Some(output);
+        }
+        thread::park_timeout(CANCEL_CHECK_INTERVAL);
+    }
+}
+
+/// The error returned by [`block_on_timeout`] when the future did not complete in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout(pub Duration);
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "future did not complete within {:?}", self.0)
+    }
+}
+
+impl std::error::Error for Timeout {}
+
+/// Blocks the current thread on `future` like [`block_on`](crate::block_on), but gives up and
+/// returns a [`Timeout`] error once `timeout` has elapsed.
+///
+/// The future is polled at least once, even with a zero `timeout`. On timeout it is dropped
+/// without being polled again, which cancels it like dropping any other future would, e.g. a
+/// [`Task`](crate::Task) being awaited is canceled.
+///
+/// ```
+/// use ens_tasks::{block_on_timeout, futures_lite::future, Timeout};
+/// use std::time::Duration;
+///
+/// let timeout = Duration::from_millis(10);
+/// assert_eq!(block_on_timeout(async { 42 }, timeout), Ok(42));
+/// assert_eq!(
+///     block_on_timeout(future::pending::<()>(), timeout),
+///     Err(Timeout(timeout))
+/// );
+/// ```
+pub fn block_on_timeout<T>(
+    future: impl Future<Output = T>,
+    timeout: Duration,
+) -> Result<T, Timeout> {
+    let deadline = Instant::now() + timeout;
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return Ok(output);
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(Timeout(timeout));
+        }
+        thread::park_timeout(remaining);
+    }
+}
+
+/// Unparks the blocked thread when the future is woken.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_output_when_not_cancelled() {
+        let cancel = AtomicBool::new(false);
+        let output = block_on_cancellable(
+            async {
+                futures_lite::future::yield_now().await;
+                7
+            },
+            &cancel,
+        );
+        assert_eq!(output, Some(7));
+    }
+
+    #[test]
+    fn returns_promptly_when_cancelled() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let canceller = {
+            let cancel = cancel.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(20));
+                cancel.store(true, Ordering::Release);
+            })
+        };
+
+        let start = Instant::now();
+        let output = block_on_cancellable(futures_lite::future::pending::<()>(), &cancel);
+        assert_eq!(output, None);
+        assert!(start.elapsed() < Duration::from_secs(1));
+        canceller.join().unwrap();
+    }
+
+    #[test]
+    fn timeout_drops_pending_future() {
+        struct SetOnDrop(Arc<AtomicBool>);
+
+        impl Drop for SetOnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::Release);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let guard = SetOnDrop(dropped.clone());
+        let future = async move {
+            let _guard = guard;
+            futures_lite::future::pending::<()>().await;
+        };
+
+        let timeout = Duration::from_millis(10);
+        let start = Instant::now();
+        assert_eq!(block_on_timeout(future, timeout), Err(Timeout(timeout)));
+        assert!(start.elapsed() >= timeout);
+        assert!(dropped.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn timeout_returns_output_of_woken_future() {
+        let (sender, receiver) = async_channel::bounded(1);
+        let sender = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(5));
+            sender.send_blocking(3).unwrap();
+        });
+
+        let output = block_on_timeout(receiver.recv(), Duration::from_secs(5));
+        assert_eq!(output, Ok(Ok(3)));
+        sender.join().unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+//! Helpers for reading files on the [`IoTaskPool`].
+//!
+//! When the `async-io` feature is enabled files are read with `async-fs` on the [`IoTaskPool`],
+//! otherwise the blocking read is handed to [`TaskPool::spawn_blocking`] so it doesn't stall one
+//! of the pool's worker threads.
+//!
+//! [`TaskPool::spawn_blocking`]: crate::TaskPool::spawn_blocking
+
+use std::{io, path::Path};
+
+use crate::{IoTaskPool, Task};
+
+/// Reads the entire contents of the file at `path` into a `Vec<u8>` on the [`IoTaskPool`].
+///
+/// # Panics
+///
+/// Panics if the [`IoTaskPool`] has not been initialized yet.
+pub fn read_to_vec(path: impl AsRef<Path>) -> Task<io::Result<Vec<u8>>> {
+    let path = path.as_ref().to_owned();
+    #[cfg(feature = "async-io")]
+    return IoTaskPool::get().spawn(async_fs::read(path));
+    #[cfg(not(feature = "async-io"))]
+    return IoTaskPool::get().spawn_blocking(move || std::fs::read(path));
+}
+
+/// Reads the entire contents of the file at `path` into a `String` on the [`IoTaskPool`].
+///
+/// Returns an error of kind [`io::ErrorKind::InvalidData`] if the file is not valid UTF-8.
+///
+/// # Panics
+///
+/// Panics if the [`IoTaskPool`] has not been initialized yet.
+pub fn read_to_string(path: impl AsRef<Path>) -> Task<io::Result<String>> {
+    let path = path.as_ref().to_owned();
+    #[cfg(feature = "async-io")]
+    return IoTaskPool::get().spawn(async_fs::read_to_string(path));
+    #[cfg(not(feature = "async-io"))]
+    return IoTaskPool::get().spawn_blocking(move || std::fs::read_to_string(path));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{block_on, TaskPool};
+
+    #[test]
+    fn read_temp_file() {
+        IoTaskPool::get_or_init(TaskPool::new);
+
+        let path = std::env::temp_dir().join("ens_tasks_io_read_temp_file.txt");
+        std::fs::write(&path, "hello ens").unwrap();
+
+        let bytes = block_on(read_to_vec(&path)).unwrap();
+        let string = block_on(read_to_string(&path)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(bytes, b"hello ens");
+        assert_eq!(string, "hello ens");
+    }
+}
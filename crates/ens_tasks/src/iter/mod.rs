@@ -474,8 +474,37 @@ where
         }
     }
 
+    /// Reduces the items of a parallel iterator to a single value with the associative `op`.
+    ///
+    /// Each batch is folded in parallel, starting from a value produced by `identity`, and the
+    /// batch results are then combined in batch order on the calling thread. Since the order
+    /// doesn't depend on which task finishes first, the result is reproducible across runs, even
+    /// for operations like floating-point addition that are only approximately associative.
+    ///
+    /// Returns the `identity` value if there are no items.
+    ///
+    /// See [`Iterator::reduce()`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.reduce)
+    fn reduce<ID, OP>(mut self, pool: &TaskPool, identity: ID, op: OP) -> BatchIter::Item
+    where
+        ID: Fn() -> BatchIter::Item + Send + Sync,
+        OP: Fn(BatchIter::Item, BatchIter::Item) -> BatchIter::Item + Send + Sync,
+        BatchIter::Item: Send + 'static,
+    {
+        let (identity, op) = (&identity, &op);
+        pool.scope(|s| {
+            while let Some(batch) = self.next_batch() {
+                s.spawn(async move { batch.fold(identity(), op) });
+            }
+        })
+        .into_iter()
+        .fold(identity(), op)
+    }
+
     /// Sums the items of a parallel iterator.
     ///
+    /// Like [`ParallelIterator::reduce`], the batch sums are added up in batch order, so the
+    /// result is reproducible across runs.
+    ///
     /// See [`Iterator::sum()`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.sum)
     fn sum<S, R>(mut self, pool: &TaskPool) -> R
     where
@@ -508,3 +537,44 @@ where
         .product()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Range;
+
+    struct Batches {
+        range: Range<u64>,
+        batch_size: u64,
+    }
+
+    impl ParallelIterator<Range<u64>> for Batches {
+        fn next_batch(&mut self) -> Option<Range<u64>> {
+            if self.range.is_empty() {
+                return None;
+            }
+            let end = self.range.end.min(self.range.start + self.batch_size);
+            let batch = self.range.start..end;
+            self.range.start = end;
+            Some(batch)
+        }
+    }
+
+    #[test]
+    fn reduce_and_sum_match_sequential_sum() {
+        let pool = TaskPool::new();
+        let batches = || Batches {
+            range: 0..10_000,
+            batch_size: 64,
+        };
+        let expected: u64 = (0..10_000).sum();
+
+        assert_eq!(batches().reduce(&pool, || 0, |a, b| a + b), expected);
+        assert_eq!(batches().sum::<u64, u64>(&pool), expected);
+        let empty = Batches {
+            range: 0..0,
+            batch_size: 64,
+        };
+        assert_eq!(empty.reduce(&pool, || 7, |a, b| a + b), 7);
+    }
+}
@@ -6,7 +6,10 @@ mod slice;
 pub use slice::{ParallelSlice, ParallelSliceMut};
 
 mod task;
-pub use task::Task;
+pub use task::{join_all, CancelHandle, Task};
+
+mod semaphore;
+pub use semaphore::{Semaphore, SemaphorePermit};
 
 #[cfg(feature = "multi-threaded")]
 mod task_pool_options;
@@ -37,6 +40,9 @@ pub use usages::ComputeTaskPool;
 #[cfg(feature = "io_task_pool")]
 pub use usages::IoTaskPool;
 
+#[cfg(feature = "io_utils")]
+pub mod io;
+
 #[cfg(feature = "multi-threaded")]
 mod thread_executor;
 #[cfg(feature = "multi-threaded")]
@@ -48,6 +54,39 @@ pub use async_io::block_on;
 pub use futures_lite::future::block_on;
 pub use futures_lite::future::poll_once;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod cancellable;
+#[cfg(not(target_arch = "wasm32"))]
+pub use cancellable::{block_on_cancellable, block_on_timeout, Timeout};
+
+/// Yields once to the executor, letting other tasks make progress before this one continues.
+///
+/// Tasks are only switched at `.await` points, so a CPU-bound task that never awaits keeps its
+/// worker busy until it finishes, starving every other task queued on the same worker. Awaiting
+/// `yield_now()` every so often inside long loops keeps the executor fair:
+///
+/// ```
+/// use ens_tasks::{yield_now, TaskPool};
+///
+/// let pool = TaskPool::new();
+/// let sums = pool.scope(|scope| {
+///     scope.spawn(async {
+///         let mut sum = 0u64;
+///         for i in 0..100_000u64 {
+///             sum += i;
+///             if i % 1024 == 0 {
+///                 yield_now().await;
+///             }
+///         }
+///         sum
+///     });
+/// });
+/// assert_eq!(sums, vec![4_999_950_000]);
+/// ```
+///
+/// Yielding has a cost, so prefer doing it once per batch of work rather than every iteration.
+pub use futures_lite::future::yield_now;
+
 mod iter;
 #[cfg(feature = "multi-threaded")]
 pub use iter::ParallelIterator;
@@ -68,15 +107,47 @@ pub mod prelude {
 
 use std::num::NonZeroUsize;
 
+/// The environment variable that overrides [`available_parallelism`].
+#[cfg(feature = "multi-threaded")]
+pub const NUM_THREADS_ENV_VAR: &str = "ENS_NUM_THREADS";
+
 /// Gets the logical CPU core count available to the current process.
 ///
-/// This is identical to [`std::thread::available_parallelism`], except
+/// If the [`ENS_NUM_THREADS`](NUM_THREADS_ENV_VAR) environment variable is set to a positive
+/// number, that number is returned instead. This is useful in containers, where the reported
+/// count is often the host's rather than the CPU quota. Other values are logged and ignored.
+///
+/// Otherwise this is identical to [`std::thread::available_parallelism`], except
 /// it will return a default value of 1 if it internally errors out.
 ///
 /// This will always return at least 1.
 #[cfg(feature = "multi-threaded")]
 pub fn available_parallelism() -> usize {
+    available_parallelism_with(|name| std::env::var(name).ok())
+}
+
+/// Like [`available_parallelism`], but looks up environment variables with `env_var`.
+#[cfg(feature = "multi-threaded")]
+pub(crate) fn available_parallelism_with(env_var: impl FnOnce(&str) -> Option<String>) -> usize {
+    if let Some(threads) = env_var(NUM_THREADS_ENV_VAR).and_then(|value| parse_num_threads(&value))
+    {
+        return threads;
+    }
+
     std::thread::available_parallelism()
         .map(NonZeroUsize::get)
         .unwrap_or(1)
 }
+
+/// Parses a value of the [`ENS_NUM_THREADS`](NUM_THREADS_ENV_VAR) environment variable, logging
+/// and ignoring anything but a positive number.
+#[cfg(feature = "multi-threaded")]
+pub(crate) fn parse_num_threads(value: &str) -> Option<usize> {
+    match value.trim().parse::<NonZeroUsize>() {
+        Ok(threads) => Some(threads.get()),
+        Err(err) => {
+            log::warn!("ignoring {NUM_THREADS_ENV_VAR}={value:?}: {err}");
+            None
+        }
+    }
+}
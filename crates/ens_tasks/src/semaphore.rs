@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+/// An async counting semaphore, used to cap how many tasks run a section of code at once.
+///
+/// Cloning a [`Semaphore`] returns a handle to the same set of permits, so it can be moved into
+/// spawned tasks. See [`TaskPool::spawn_limited`](crate::TaskPool::spawn_limited) for the common
+/// case of limiting how many spawned futures run concurrently.
+///
+/// # Fairness
+///
+/// Tasks waiting in [`Semaphore::acquire`] are woken in the order they started waiting, so a
+/// waiter is never starved by later waiters. Acquisition is not strictly first-come first-served
+/// though: a task that calls [`Semaphore::acquire`] or [`Semaphore::try_acquire`] right as a
+/// permit is released may take it before the woken waiter gets to run.
+#[derive(Debug, Clone)]
+pub struct Semaphore(Arc<async_lock::Semaphore>);
+
+impl Semaphore {
+    /// Creates a semaphore with `permits` permits.
+    pub fn new(permits: usize) -> Self {
+        Self(Arc::new(async_lock::Semaphore::new(permits)))
+    }
+
+    /// Waits until a permit is available and takes it. The permit is returned to the semaphore
+    /// when the returned [`SemaphorePermit`] is dropped.
+    pub async fn acquire(&self) -> SemaphorePermit {
+        SemaphorePermit {
+            _guard: self.0.acquire_arc().await,
+        }
+    }
+
+    /// Takes a permit if one is available right now.
+    pub fn try_acquire(&self) -> Option<SemaphorePermit> {
+        self.0
+            .try_acquire_arc()
+            .map(|guard| SemaphorePermit { _guard: guard })
+    }
+
+    /// Adds `permits` new permits to the semaphore, waking waiters as needed.
+    pub fn add_permits(&self, permits: usize) {
+        self.0.add_permits(permits);
+    }
+}
+
+/// A permit taken from a [`Semaphore`]. Dropping it returns the permit.
+#[derive(Debug)]
+#[must_use = "The permit is returned to the semaphore as soon as it is dropped."]
+pub struct SemaphorePermit {
+    _guard: async_lock::SemaphoreGuardArc,
+}
@@ -1,6 +1,9 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::{cell::RefCell, future::Future, marker::PhantomData, mem, rc::Rc};
 
+use crate::{CancelHandle, Semaphore};
+
 thread_local! {
     static LOCAL_EXECUTOR: async_executor::LocalExecutor<'static> = async_executor::LocalExecutor::new();
 }
@@ -129,23 +132,46 @@ impl TaskPool {
         let results = scope.results.borrow();
         results
             .iter()
-            .map(|result| result.borrow_mut().take().unwrap())
+            // cancelled tasks leave no result
+            .filter_map(|result| result.borrow_mut().take())
             .collect()
     }
 
+    /// Like [`TaskPool::scope`], and returns `true` alongside the results if running the scope
+    /// took longer than `deadline`.
+    ///
+    /// Unlike the multi-threaded task pool, tasks are not cancelled when the deadline passes,
+    /// since they are run to completion on this thread before the deadline is checked.
+    pub fn scope_with_deadline<'env, F, T>(&self, deadline: Duration, f: F) -> (Vec<T>, bool)
+    where
+        F: for<'scope> FnOnce(&'env mut Scope<'scope, 'env, T>),
+        T: Send + 'static,
+    {
+        let start = Instant::now();
+        let results = self.scope(f);
+        (results, start.elapsed() > deadline)
+    }
+
     /// Spawns a static future onto the thread pool. The returned Task is a future. It can also be
     /// cancelled and "detached" allowing it to continue running without having to be polled by the
     /// end-user.
     ///
     /// If the provided future is non-`Send`, [`TaskPool::spawn_local`] should be used instead.
-    pub fn spawn<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask
+    pub fn spawn<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask<T>
     where
         T: 'static,
     {
+        let output = Arc::new(Mutex::new(None));
+        let future = {
+            let output = output.clone();
+            async move {
+                let value = future.await;
+                *output.lock().unwrap() = Some(value);
+            }
+        };
+
         #[cfg(target_arch = "wasm32")]
-        wasm_bindgen_futures::spawn_local(async move {
-            future.await;
-        });
+        wasm_bindgen_futures::spawn_local(future);
 
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -156,11 +182,37 @@ impl TaskPool {
             });
         }
 
-        FakeTask
+        FakeTask(output)
+    }
+
+    /// Spawns a static future like [`TaskPool::spawn`], but only starts running it once a permit
+    /// has been taken from `semaphore`. The permit is held until the future completes.
+    pub fn spawn_limited<T>(
+        &self,
+        semaphore: &Semaphore,
+        future: impl Future<Output = T> + 'static,
+    ) -> FakeTask<T>
+    where
+        T: 'static,
+    {
+        let semaphore = semaphore.clone();
+        self.spawn(async move {
+            let _permit = semaphore.acquire().await;
+            future.await
+        })
+    }
+
+    /// Runs the blocking function `f` to completion right away, since there are no other threads
+    /// to run it on. The returned task is already finished.
+    pub fn spawn_blocking<T>(&self, f: impl FnOnce() -> T + 'static) -> FakeTask<T>
+    where
+        T: 'static,
+    {
+        FakeTask(Arc::new(Mutex::new(Some(f()))))
     }
 
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskPool::spawn`].
-    pub fn spawn_local<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask
+    pub fn spawn_local<T>(&self, future: impl Future<Output = T> + 'static) -> FakeTask<T>
     where
         T: 'static,
     {
@@ -186,15 +238,42 @@ impl TaskPool {
     }
 }
 
-/// An empty task used in single-threaded contexts.
+/// A task used in single-threaded contexts.
 ///
-/// This does nothing and is therefore safe, and recommended, to ignore.
-#[derive(Debug)]
-pub struct FakeTask;
+/// This only holds the output of the spawned future, which is driven by the local executor or, on
+/// wasm, the JS event loop. It is safe, and recommended, to ignore.
+pub struct FakeTask<T = ()>(Arc<Mutex<Option<T>>>);
 
-impl FakeTask {
+impl<T> FakeTask<T> {
     /// No op on the single threaded task pool
     pub fn detach(self) {}
+
+    /// Returns `true` if the spawned future completed.
+    pub fn is_finished(&self) -> bool {
+        self.0.lock().unwrap().is_some()
+    }
+
+    /// Drops the task, returning its output if it completed. This never waits, so it is the same
+    /// as [`FakeTask::cancel_now`].
+    pub async fn cancel(self) -> Option<T> {
+        self.cancel_now()
+    }
+
+    /// Drops the task, returning its output if it completed.
+    ///
+    /// Outside of wasm, a future that didn't complete when it was spawned never runs again, so
+    /// this returns [`None`] for it.
+    pub fn cancel_now(self) -> Option<T> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+impl<T> std::fmt::Debug for FakeTask<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FakeTask")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
 }
 
 /// A `TaskPool` scope for running one or more non-`'static` futures.
@@ -240,12 +319,52 @@ impl<'scope, 'env, T: Send + 'env> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn_on_scope<Fut: Future<Output = T> + 'scope>(&self, f: Fut) {
+        self.spawn_optional(async move { Some(f.await) });
+    }
+
+    /// Spawns a scoped future onto the executor like [`Scope::spawn`], and returns a
+    /// [`CancelHandle`] to stop it early. A cancelled future contributes no result.
+    ///
+    /// For more information, see [`TaskPool::scope`].
+    pub fn spawn_cancellable<Fut: Future<Output = T> + 'scope>(&self, f: Fut) -> CancelHandle {
+        let (handle, f) = CancelHandle::wrap(f);
+        self.spawn_optional(f);
+        handle
+    }
+
+    fn spawn_optional<Fut: Future<Output = Option<T>> + 'scope>(&self, f: Fut) {
         let result = Rc::new(RefCell::new(None));
         self.results.borrow_mut().push(result.clone());
         let f = async move {
-            let temp_result = f.await;
-            result.borrow_mut().replace(temp_result);
+            if let Some(temp_result) = f.await {
+                result.borrow_mut().replace(temp_result);
+            }
         };
         self.executor.spawn(f).detach();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fake_task_keeps_finished_output() {
+        let pool = TaskPool::new();
+
+        let task = pool.spawn(async { 7 });
+        assert!(task.is_finished());
+        assert_eq!(task.cancel_now(), Some(7));
+
+        let task = pool.spawn(futures_lite::future::pending::<()>());
+        assert!(!task.is_finished());
+        assert_eq!(futures_lite::future::block_on(task.cancel()), None);
+    }
+
+    #[test]
+    fn fake_task_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<FakeTask>();
+        assert_send_sync::<FakeTask<u32>>();
+    }
+}
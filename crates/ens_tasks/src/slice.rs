@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use super::TaskPool;
 
 /// Provides functions for mapping read-only slices across a provided [`TaskPool`].
@@ -47,6 +49,52 @@ pub trait ParallelSlice<T: Sync>: AsRef<[T]> {
         })
     }
 
+    /// Splits the slice into one chunk per thread in `task_pool`, but never into chunks shorter
+    /// than `min_chunk_len` except for the last one, and maps the chunks in parallel across the
+    /// provided `task_pool`.
+    ///
+    /// If that leaves a single chunk, such as when the slice is shorter than `min_chunk_len`, it
+    /// is mapped on the calling thread without spawning a task. This avoids paying the scheduling
+    /// overhead for small slices in hot systems.
+    ///
+    /// The iteration function takes the index of the chunk in the original slice as the
+    /// first argument, and the chunk as the second argument.
+    ///
+    /// Returns a `Vec` of the mapped results in the same order as the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ens_tasks::{ParallelSlice, TaskPool};
+    /// let task_pool = TaskPool::new();
+    /// let counts = (0..10000).collect::<Vec<u32>>();
+    /// let sums = counts.par_chunk_map_min(&task_pool, 256, |_index, chunk| {
+    ///   chunk.iter().sum::<u32>()
+    /// });
+    /// # assert_eq!(sums.iter().sum::<u32>(), (0..10000).sum::<u32>());
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// [`ParallelSlice::par_chunk_map`] for mapping with a fixed chunk size.
+    fn par_chunk_map_min<F, R>(&self, task_pool: &TaskPool, min_chunk_len: usize, f: F) -> Vec<R>
+    where
+        F: Fn(usize, &[T]) -> R + Send + Sync,
+        R: Send + 'static,
+    {
+        let slice = self.as_ref();
+        let chunk_size = slice
+            .len()
+            .div_ceil(task_pool.thread_num().max(1))
+            .max(min_chunk_len)
+            .max(1);
+
+        if !slice.is_empty() && slice.len() <= chunk_size {
+            return vec![f(0, slice)];
+        }
+        slice.par_chunk_map(task_pool, chunk_size, f)
+    }
+
     /// Splits the slice into a maximum of `max_tasks` chunks, and maps the chunks in parallel
     /// across the provided `task_pool`. One task is spawned in the task pool for every chunk.
     ///
@@ -96,6 +144,50 @@ pub trait ParallelSlice<T: Sync>: AsRef<[T]> {
 
         slice.par_chunk_map(task_pool, chunk_size, f)
     }
+
+    /// Like [`ParallelSlice::par_splat_map`], but also measures the wall time each chunk took
+    /// to map.
+    ///
+    /// Returns a `Vec` of the mapped results in the same order as the input, along with a `Vec`
+    /// holding the elapsed time of every chunk in the same order.
+    ///
+    /// Timing every task adds overhead, so this is meant as an aid for tuning chunk sizes of
+    /// workloads with data-dependent cost rather than for use in production code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ens_tasks::{ParallelSlice, TaskPool};
+    /// let task_pool = TaskPool::new();
+    /// let counts = (0..10000).collect::<Vec<u32>>();
+    /// let (sums, timings) = counts.par_splat_map_timed(&task_pool, Some(4), |_index, chunk| {
+    ///   chunk.iter().sum::<u32>()
+    /// });
+    /// assert_eq!(sums.len(), timings.len());
+    /// # assert_eq!(sums.iter().sum::<u32>(), (0..10000).sum::<u32>());
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// [`ParallelSlice::par_splat_map`] for mapping without measuring chunk timings.
+    fn par_splat_map_timed<F, R>(
+        &self,
+        task_pool: &TaskPool,
+        max_tasks: Option<usize>,
+        f: F,
+    ) -> (Vec<R>, Vec<Duration>)
+    where
+        F: Fn(usize, &[T]) -> R + Send + Sync,
+        R: Send + 'static,
+    {
+        self.par_splat_map(task_pool, max_tasks, |index, chunk| {
+            let start = Instant::now();
+            let result = f(index, chunk);
+            (result, start.elapsed())
+        })
+        .into_iter()
+        .unzip()
+    }
 }
 
 impl<S, T: Sync> ParallelSlice<T> for S where S: AsRef<[T]> {}
@@ -231,6 +323,24 @@ mod tests {
         assert_eq!(sum, 1000 * 42);
     }
 
+    #[test]
+    fn test_par_chunk_map_min_runs_short_slices_inline() {
+        let v = [1, 2, 3];
+        let task_pool = TaskPool::new();
+        let caller = std::thread::current().id();
+
+        let outputs = v.par_chunk_map_min(&task_pool, 8, |index, numbers| {
+            assert_eq!(std::thread::current().id(), caller);
+            (index, numbers.len())
+        });
+        assert_eq!(outputs, [(0, 3)]);
+
+        let v = vec![1; 100];
+        let outputs = v.par_chunk_map_min(&task_pool, 30, |_, numbers| numbers.len());
+        assert!(outputs[..outputs.len() - 1].iter().all(|len| *len >= 30));
+        assert_eq!(outputs.iter().sum::<usize>(), 100);
+    }
+
     #[test]
     fn test_par_chunks_map_mut() {
         let mut v = vec![42; 1000];
@@ -262,4 +372,17 @@ mod tests {
 
         assert_eq!(outputs.iter().sum::<i32>(), 100 * (9 * 10) / 2);
     }
+
+    #[test]
+    fn test_par_splat_map_timed() {
+        let v = vec![1; 1000];
+        let task_pool = TaskPool::new();
+        let (outputs, timings) = v.par_splat_map_timed(&task_pool, Some(10), |_, numbers| -> i32 {
+            numbers.iter().sum()
+        });
+
+        assert!(!outputs.is_empty());
+        assert_eq!(timings.len(), outputs.len());
+        assert_eq!(outputs.iter().sum::<i32>(), 1000);
+    }
 }
@@ -1,5 +1,5 @@
 use std::{
-    future::Future,
+    future::{Future, IntoFuture},
     pin::Pin,
     task::{Context, Poll},
 };
@@ -9,7 +9,9 @@ use std::{
 /// Tasks are also futures themselves and yield the output of the spawned future.
 ///
 /// When a task is dropped, its gets canceled and won't be polled again. To cancel a task a bit
-/// more gracefully and wait until it stops running, use the [`Task::cancel()`] method.
+/// more gracefully and wait until it stops running, use the [`Task::cancel()`] method. To cancel
+/// it without waiting, but still get the output of a task that already completed, use
+/// [`Task::cancel_now()`].
 ///
 /// Tasks that panic get immediately canceled. Awaiting a canceled task also causes a panic.
 /// Wraps `async_executor::Task`
@@ -45,8 +47,24 @@ impl<T> Task<T> {
         self.0.cancel().await
     }
 
-    /// Returns `true` if the current task is finished.
+    /// Cancels the task without waiting for it, returning its output if it already completed.
+    ///
+    /// An unfinished task won't be polled again, but this doesn't block while it is being polled
+    /// on another thread; that poll runs to its end and its output is dropped. Returns [`None`] if
+    /// the task didn't complete or panicked.
     ///
+    /// This can be used to drive a task from a frame loop: check [`Task::is_finished`] each frame
+    /// and call this once it returns `true`, or to give up on the task.
+    pub fn cancel_now(self) -> Option<T> {
+        if !self.0.is_finished() {
+            return None;
+        }
+        // A finished task resolves on its first poll, so this never blocks
+        let mut task = self.0.fallible();
+        futures_lite::future::block_on(futures_lite::future::poll_once(&mut task)).flatten()
+    }
+
+    /// Returns `true` if the current task is finished.
     ///
     /// Unlike poll, it doesn't resolve the final value, it just checks if the task has finished.
     /// Note that in a multithreaded environment, this task can be finished immediately after calling this function.
@@ -64,3 +82,126 @@ impl<T> Future for Task<T> {
         Pin::new(&mut self.0).poll(cx)
     }
 }
+
+/// Waits for every task in `tasks` to finish and returns their outputs in the same order.
+///
+/// The tasks keep running concurrently on their executors while this waits on them one by one,
+/// so the total wait is that of the slowest task. This accepts anything that can be awaited, so it
+/// works with [`Task`]s from any executor as well as plain futures, and can be awaited from another
+/// task or driven with [`block_on`](crate::block_on). Plain futures only make progress while they
+/// are awaited, so they run one after the other.
+///
+/// If any task panics, awaiting it panics as well and the remaining tasks are canceled when
+/// dropped.
+pub async fn join_all<F: IntoFuture>(tasks: impl IntoIterator<Item = F>) -> Vec<F::Output> {
+    let tasks = tasks.into_iter();
+    let mut results = Vec::with_capacity(tasks.size_hint().0);
+    for task in tasks {
+        results.push(task.await);
+    }
+    results
+}
+
+/// A handle to cancel a scoped task spawned with `Scope::spawn_cancellable`.
+///
+/// Cancelling takes effect the next time the task is polled, so a task is never interrupted
+/// in the middle of a poll. A cancelled task contributes no result to its scope. Dropping every
+/// handle to a task leaves it running.
+#[derive(Debug, Clone)]
+pub struct CancelHandle(async_channel::Sender<()>);
+
+impl CancelHandle {
+    /// Wraps `future` so that it resolves to [`None`] once the returned handle is cancelled.
+    pub(crate) fn wrap<T>(
+        future: impl Future<Output = T>,
+    ) -> (Self, impl Future<Output = Option<T>>) {
+        use futures_lite::FutureExt;
+
+        let (cancel_tx, cancel_rx) = async_channel::bounded(1);
+        let cancelled = async move {
+            // A closed channel means every handle was dropped without cancelling
+            if cancel_rx.recv().await.is_err() {
+                futures_lite::future::pending::<()>().await;
+            }
+            None
+        };
+        (
+            Self(cancel_tx),
+            async move { Some(future.await) }.or(cancelled),
+        )
+    }
+
+    /// Cancels the task. Does nothing if it already finished or was cancelled.
+    pub fn cancel(&self) {
+        let _ = self.0.try_send(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future;
+
+    #[test]
+    fn join_all_preserves_order() {
+        let executor = async_executor::LocalExecutor::new();
+        let tasks: Vec<_> = (0..10)
+            .map(|index| {
+                Task::new(executor.spawn(async move {
+                    // Make later tasks finish first.
+                    for _ in 0..(10 - index) {
+                        future::yield_now().await;
+                    }
+                    index
+                }))
+            })
+            .collect();
+
+        let results = future::block_on(executor.run(join_all(tasks)));
+        assert_eq!(results, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn join_all_accepts_plain_futures() {
+        let futures = (0..3).map(|index| async move { index * 2 });
+        assert_eq!(future::block_on(join_all(futures)), [0, 2, 4]);
+    }
+
+    #[test]
+    fn cancel_now_returns_output_of_finished_task() {
+        let executor = async_executor::Executor::new();
+        let task = Task::new(executor.spawn(async { 7 }));
+        assert!(!task.is_finished());
+
+        while executor.try_tick() {}
+        assert!(task.is_finished());
+        assert_eq!(task.cancel_now(), Some(7));
+    }
+
+    #[test]
+    fn cancel_now_stops_unfinished_task() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        let executor = async_executor::Executor::new();
+        let polls = Arc::new(AtomicUsize::new(0));
+        let task = Task::new(executor.spawn({
+            let polls = polls.clone();
+            async move {
+                loop {
+                    polls.fetch_add(1, Ordering::SeqCst);
+                    future::yield_now().await;
+                }
+            }
+        }));
+        executor.try_tick();
+        assert!(!task.is_finished());
+
+        assert_eq!(task.cancel_now(), None::<()>);
+        let polled = polls.load(Ordering::SeqCst);
+        while executor.try_tick() {}
+        assert_eq!(polls.load(Ordering::SeqCst), polled);
+    }
+}
@@ -1,10 +1,14 @@
 use std::{
+    any::Any,
+    cell::RefCell,
     future::Future,
+    iter,
     marker::PhantomData,
     mem,
     panic::AssertUnwindSafe,
-    sync::Arc,
+    sync::{Arc, Mutex},
     thread::{self, JoinHandle},
+    time::Duration,
 };
 
 use async_task::FallibleTask;
@@ -14,9 +18,13 @@ use futures_lite::FutureExt;
 use crate::{
     block_on,
     thread_executor::{ThreadExecutor, ThreadExecutorTicker},
-    Task,
+    CancelHandle, Semaphore, Task,
 };
 
+/// A task spawned on a [`Scope`], resolving to [`None`] if it was cancelled through its
+/// [`CancelHandle`] and to the panic payload if it panicked.
+type ScopeTask<T> = FallibleTask<Result<Option<T>, Box<dyn Any + Send>>>;
+
 struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
 
 impl Drop for CallOnDrop {
@@ -32,7 +40,7 @@ impl Drop for CallOnDrop {
 #[must_use]
 pub struct TaskPoolBuilder {
     /// If set, we'll set up the thread pool to use at most `num_threads` threads.
-    /// Otherwise use the logical core count of the system
+    /// Otherwise use [`available_parallelism`](crate::available_parallelism)
     num_threads: Option<usize>,
     /// If set, we'll use the given stack size rather than the system default
     stack_size: Option<usize>,
@@ -40,8 +48,13 @@ pub struct TaskPoolBuilder {
     /// be named <thread_name> (<thread_index>), i.e. "MyThreadPool (2)"
     thread_name: Option<String>,
 
-    on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    on_thread_spawn: Option<Arc<dyn Fn(usize) + Send + Sync + 'static>>,
     on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    on_thread_panic: Option<Arc<dyn Fn(&(dyn Any + Send)) + Send + Sync + 'static>>,
+
+    /// Looks up environment variables when `num_threads` is unset. Uses [`std::env::var`] if
+    /// unset, tests replace it to avoid touching the process environment.
+    env_var: Option<fn(&str) -> Option<String>>,
 }
 
 impl TaskPoolBuilder {
@@ -50,8 +63,9 @@ impl TaskPoolBuilder {
         Self::default()
     }
 
-    /// Override the number of threads created for the pool. If unset, we default to the number
-    /// of logical cores of the system
+    /// Override the number of threads created for the pool. If unset, we default to
+    /// [`available_parallelism`](crate::available_parallelism), which can be overridden with the
+    /// `ENS_NUM_THREADS` environment variable
     pub fn num_threads(mut self, num_threads: usize) -> Self {
         self.num_threads = Some(num_threads);
         self
@@ -70,11 +84,16 @@ impl TaskPoolBuilder {
         self
     }
 
-    /// Sets a callback that is invoked once for every created thread as it starts.
+    /// Sets a callback that is invoked once for every created thread as it starts, with the index
+    /// of the thread within the pool.
     ///
     /// This is called on the thread itself and has access to all thread-local storage.
     /// This will block running async tasks on the thread until the callback completes.
-    pub fn on_thread_spawn(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+    ///
+    /// The index is in `0..num_threads` and matches the one in the thread name, which makes this
+    /// the place to pin workers to specific cores (e.g. the performance cores of a heterogeneous
+    /// CPU) with a crate such as `core_affinity`.
+    pub fn on_thread_spawn(mut self, f: impl Fn(usize) + Send + Sync + 'static) -> Self {
         self.on_thread_spawn = Some(Arc::new(f));
         self
     }
@@ -88,12 +107,117 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// Sets a callback that is invoked with the panic payload whenever a task spawned with
+    /// [`TaskPool::spawn`] panics.
+    ///
+    /// This is called on the thread that polled the task, usually one of the named pool threads
+    /// as returned by [`thread::current`], before the panic is propagated to whoever awaits the
+    /// [`Task`]. The thread then keeps running other tasks. The payload is usually a `&str` or a
+    /// `String` holding the panic message.
+    ///
+    /// Unlike a global [panic hook](std::panic::set_hook), this only sees panics of this pool's
+    /// tasks. Panics of tasks spawned on a [`Scope`] are not seen here, since they are
+    /// propagated to the caller of [`TaskPool::scope`] instead.
+    pub fn on_thread_panic(
+        mut self,
+        f: impl Fn(&(dyn Any + Send)) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_thread_panic = Some(Arc::new(f));
+        self
+    }
+
+    /// Override how environment variables such as
+    /// [`ENS_NUM_THREADS`](crate::NUM_THREADS_ENV_VAR) are looked up.
+    #[cfg(test)]
+    pub(crate) fn env_var(mut self, env_var: fn(&str) -> Option<String>) -> Self {
+        self.env_var = Some(env_var);
+        self
+    }
+
     /// Creates a new [`TaskPool`] based on the current options.
     pub fn build(self) -> TaskPool {
         TaskPool::new_internal(self)
     }
 }
 
+/// The part of a [`TaskPoolBuilder`] still needed after the pool is built, e.g. to spawn more
+/// worker threads.
+struct WorkerConfig {
+    stack_size: Option<usize>,
+    thread_name: Option<String>,
+    on_thread_spawn: Option<Arc<dyn Fn(usize) + Send + Sync + 'static>>,
+    on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    on_thread_panic: Option<Arc<dyn Fn(&(dyn Any + Send)) + Send + Sync + 'static>>,
+}
+
+impl WorkerConfig {
+    fn spawn(&self, executor: &Arc<async_executor::Executor<'static>>, index: usize) -> Worker {
+        let ex = Arc::clone(executor);
+        let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
+
+        let thread_name = if let Some(thread_name) = self.thread_name.as_deref() {
+            format!("{thread_name} ({index})")
+        } else {
+            format!("TaskPool ({index})")
+        };
+        let mut thread_builder = thread::Builder::new().name(thread_name);
+
+        if let Some(stack_size) = self.stack_size {
+            thread_builder = thread_builder.stack_size(stack_size);
+        }
+
+        let on_thread_spawn = self.on_thread_spawn.clone();
+        let on_thread_destroy = self.on_thread_destroy.clone();
+
+        let handle = thread_builder
+            .spawn(move || {
+                TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
+                    if let Some(on_thread_spawn) = on_thread_spawn {
+                        on_thread_spawn(index);
+                        drop(on_thread_spawn);
+                    }
+                    let _destructor = CallOnDrop(on_thread_destroy);
+                    loop {
+                        let res = std::panic::catch_unwind(|| {
+                            let tick_forever = async move {
+                                loop {
+                                    local_executor.tick().await;
+                                }
+                            };
+                            block_on(ex.run(tick_forever.or(shutdown_rx.recv())))
+                        });
+                        if let Ok(value) = res {
+                            // Use unwrap_err because we expect a Closed error
+                            value.unwrap_err();
+                            break;
+                        }
+                    }
+                });
+            })
+            .expect("Failed to spawn thread.");
+
+        Worker {
+            handle,
+            shutdown_tx,
+        }
+    }
+}
+
+/// A worker thread of a [`TaskPool`], which exits once its shutdown channel is closed.
+#[derive(Debug)]
+struct Worker {
+    handle: JoinHandle<()>,
+    shutdown_tx: async_channel::Sender<()>,
+}
+
+impl Worker {
+    /// Stops the worker and waits for the task it is currently running to finish.
+    fn retire(self) -> thread::Result<()> {
+        self.shutdown_tx.close();
+        self.handle.join()
+    }
+}
+
 /// A thread pool for executing tasks.
 ///
 /// While futures usually need to be polled to be executed, Bevy tasks are being
@@ -104,7 +228,6 @@ impl TaskPoolBuilder {
 ///
 /// If the result is not required, one may also use [`Task::detach`] and the pool
 /// will still execute a task, even if it is dropped.
-#[derive(Debug)]
 pub struct TaskPool {
     /// The executor for the pool
     ///
@@ -114,8 +237,17 @@ pub struct TaskPool {
     executor: Arc<async_executor::Executor<'static>>,
 
     /// Inner state of the pool
-    threads: Vec<JoinHandle<()>>,
-    shutdown_tx: async_channel::Sender<()>,
+    workers: Mutex<Vec<Worker>>,
+    config: WorkerConfig,
+}
+
+impl std::fmt::Debug for TaskPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskPool")
+            .field("executor", &self.executor)
+            .field("workers", &self.workers)
+            .finish_non_exhaustive()
+    }
 }
 
 impl TaskPool {
@@ -135,72 +267,81 @@ impl TaskPool {
     }
 
     fn new_internal(builder: TaskPoolBuilder) -> Self {
-        let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
-
         let executor = Arc::new(async_executor::Executor::new());
 
         let num_threads = builder
             .num_threads
-            .unwrap_or_else(crate::available_parallelism);
-
-        let threads = (0..num_threads)
-            .map(|i| {
-                let ex = Arc::clone(&executor);
-                let shutdown_rx = shutdown_rx.clone();
-
-                let thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
-                    format!("{thread_name} ({i})")
-                } else {
-                    format!("TaskPool ({i})")
-                };
-                let mut thread_builder = thread::Builder::new().name(thread_name);
-
-                if let Some(stack_size) = builder.stack_size {
-                    thread_builder = thread_builder.stack_size(stack_size);
-                }
+            .unwrap_or_else(|| match builder.env_var {
+                Some(env_var) => crate::available_parallelism_with(env_var),
+                None => crate::available_parallelism(),
+            });
 
-                let on_thread_spawn = builder.on_thread_spawn.clone();
-                let on_thread_destroy = builder.on_thread_destroy.clone();
+        let config = WorkerConfig {
+            stack_size: builder.stack_size,
+            thread_name: builder.thread_name,
+            on_thread_spawn: builder.on_thread_spawn,
+            on_thread_destroy: builder.on_thread_destroy,
+            on_thread_panic: builder.on_thread_panic,
+        };
 
-                thread_builder
-                    .spawn(move || {
-                        TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
-                            if let Some(on_thread_spawn) = on_thread_spawn {
-                                on_thread_spawn();
-                                drop(on_thread_spawn);
-                            }
-                            let _destructor = CallOnDrop(on_thread_destroy);
-                            loop {
-                                let res = std::panic::catch_unwind(|| {
-                                    let tick_forever = async move {
-                                        loop {
-                                            local_executor.tick().await;
-                                        }
-                                    };
-                                    block_on(ex.run(tick_forever.or(shutdown_rx.recv())))
-                                });
-                                if let Ok(value) = res {
-                                    // Use unwrap_err because we expect a Closed error
-                                    value.unwrap_err();
-                                    break;
-                                }
-                            }
-                        });
-                    })
-                    .expect("Failed to spawn thread.")
-            })
+        let workers = (0..num_threads)
+            .map(|i| config.spawn(&executor, i))
             .collect();
 
         Self {
             executor,
-            threads,
-            shutdown_tx,
+            workers: Mutex::new(workers),
+            config,
         }
     }
 
+    /// Returns the name the threads of the pool were given with
+    /// [`TaskPoolBuilder::thread_name`], if any.
+    pub fn thread_name(&self) -> Option<&str> {
+        self.config.thread_name.as_deref()
+    }
+
     /// Return the number of threads owned by the task pool
     pub fn thread_num(&self) -> usize {
-        self.threads.len()
+        self.workers.lock().unwrap().len()
+    }
+
+    /// Grows or shrinks the pool to `new_thread_count` worker threads.
+    ///
+    /// New threads are configured like the ones the pool was built with, including the
+    /// [`on_thread_spawn`](TaskPoolBuilder::on_thread_spawn) callback, and continue the thread
+    /// indices where the existing ones end. Shrinking retires the threads with the highest
+    /// indices, so the indices of the remaining threads are always `0..new_thread_count`.
+    ///
+    /// When shrinking, this blocks until every retiring thread has finished the task it is
+    /// currently polling. Tasks that are queued but not running are left to the remaining
+    /// threads, but tasks spawned with [`TaskPool::spawn_local`] on a retiring thread are dropped
+    /// with it. Shrinking to zero threads leaves [`TaskPool::spawn`]ed tasks unpolled until the
+    /// pool grows again, while [`TaskPool::scope`] still makes progress on the calling thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a retiring thread panicked. Calling this from a task running on this pool may
+    /// deadlock when shrinking, since the thread could end up waiting for itself.
+    pub fn resize(&self, new_thread_count: usize) {
+        let retiring = {
+            let mut workers = self.workers.lock().unwrap();
+            let current = workers.len();
+            if new_thread_count >= current {
+                workers.extend(
+                    (current..new_thread_count).map(|i| self.config.spawn(&self.executor, i)),
+                );
+                return;
+            }
+            workers.split_off(new_thread_count)
+        };
+
+        // Join without holding the lock, since the tasks being waited for may query the pool.
+        for worker in retiring {
+            worker
+                .retire()
+                .expect("Task thread panicked while executing.");
+        }
     }
 
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
@@ -290,7 +431,40 @@ impl TaskPool {
         T: Send + 'static,
     {
         Self::THREAD_EXECUTOR.with(|scope_executor| {
-            self.scope_with_executor_inner(true, scope_executor, scope_executor, f)
+            self.scope_with_executor_inner(true, scope_executor, scope_executor, None, f)
+                .0
+        })
+    }
+
+    /// Like [`TaskPool::scope`], but stops waiting for the spawned tasks once `deadline` has
+    /// passed, and returns the results collected so far together with `true` if the deadline was
+    /// exceeded.
+    ///
+    /// When the deadline passes, every task that hasn't finished yet is cancelled. Tasks are only
+    /// interrupted between polls, so this blocks until tasks that are being polled right then
+    /// yield, which guarantees that no task outlives the scope. The results are those of the tasks
+    /// that finished before they were cancelled, in spawn order. A panic in any finished task is
+    /// propagated like in [`TaskPool::scope`].
+    ///
+    /// ```
+    /// use ens_tasks::{futures_lite::future, TaskPool};
+    /// use std::time::Duration;
+    ///
+    /// let pool = TaskPool::new();
+    /// let (results, exceeded) = pool.scope_with_deadline(Duration::from_millis(10), |s| {
+    ///     s.spawn(async { 1 });
+    ///     s.spawn(future::pending());
+    /// });
+    /// assert_eq!(results, [1]);
+    /// assert!(exceeded);
+    /// ```
+    pub fn scope_with_deadline<'env, F, T>(&self, deadline: Duration, f: F) -> (Vec<T>, bool)
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env, T>),
+        T: Send + 'static,
+    {
+        Self::THREAD_EXECUTOR.with(|scope_executor| {
+            self.scope_with_executor_inner(true, scope_executor, scope_executor, Some(deadline), f)
         })
     }
 
@@ -321,15 +495,19 @@ impl TaskPool {
                     tick_task_pool_executor,
                     external_executor,
                     scope_executor,
+                    None,
                     f,
                 )
+                .0
             } else {
                 self.scope_with_executor_inner(
                     tick_task_pool_executor,
                     scope_executor,
                     scope_executor,
+                    None,
                     f,
                 )
+                .0
             }
         })
     }
@@ -339,8 +517,9 @@ impl TaskPool {
         tick_task_pool_executor: bool,
         external_executor: &ThreadExecutor,
         scope_executor: &ThreadExecutor,
+        deadline: Option<Duration>,
         f: F,
-    ) -> Vec<T>
+    ) -> (Vec<T>, bool)
     where
         F: for<'scope> FnOnce(&'scope Scope<'scope, 'env, T>),
         T: Send + 'static,
@@ -360,13 +539,10 @@ impl TaskPool {
             unsafe { mem::transmute(external_executor) };
         // SAFETY: As above, all futures must complete in this function so we can change the lifetime
         let scope_executor: &'env ThreadExecutor<'env> = unsafe { mem::transmute(scope_executor) };
-        let spawned: ConcurrentQueue<FallibleTask<Result<T, Box<(dyn std::any::Any + Send)>>>> =
-            ConcurrentQueue::unbounded();
+        let spawned: ConcurrentQueue<ScopeTask<T>> = ConcurrentQueue::unbounded();
         // shadow the variable so that the owned value cannot be used for the rest of the function
         // SAFETY: As above, all futures must complete in this function so we can change the lifetime
-        let spawned: &'env ConcurrentQueue<
-            FallibleTask<Result<T, Box<(dyn std::any::Any + Send)>>>,
-        > = unsafe { mem::transmute(&spawned) };
+        let spawned: &'env ConcurrentQueue<ScopeTask<T>> = unsafe { mem::transmute(&spawned) };
 
         let scope = Scope {
             executor,
@@ -384,25 +560,44 @@ impl TaskPool {
         f(scope);
 
         if spawned.is_empty() {
-            Vec::new()
+            (Vec::new(), false)
         } else {
             block_on(async move {
+                let results = RefCell::new(Vec::with_capacity(spawned.len()));
+                let push_result = |res: Result<Option<T>, Box<dyn Any + Send>>| match res {
+                    Ok(Some(res)) => results.borrow_mut().push(res),
+                    // cancelled through its `CancelHandle`
+                    Ok(None) => {}
+                    Err(payload) => std::panic::resume_unwind(payload),
+                };
+                // Holds the task being awaited, so it can still be cancelled if the deadline passes
+                let current = RefCell::new(None);
+
+                // `current` is only used again once this future has been dropped
+                #[allow(clippy::await_holding_refcell_ref)]
                 let get_results = async {
-                    let mut results = Vec::with_capacity(spawned.len());
                     while let Ok(task) = spawned.pop() {
-                        if let Some(res) = task.await {
-                            match res {
-                                Ok(res) => results.push(res),
-                                Err(payload) => std::panic::resume_unwind(payload),
-                            }
-                        } else {
+                        let mut current = current.borrow_mut();
+                        let Some(res) = current.insert(task).await else {
                             panic!("Failed to catch panic!");
+                        };
+                        *current = None;
+                        push_result(res);
+                    }
+                    true
+                };
+                let deadline_passed = async {
+                    match deadline {
+                        Some(deadline) => {
+                            async_io::Timer::after(deadline).await;
                         }
+                        None => futures_lite::future::pending().await,
                     }
-                    results
+                    false
                 };
+                let get_results = get_results.or(deadline_passed);
 
-                let tick_task_pool_executor = tick_task_pool_executor || self.threads.is_empty();
+                let tick_task_pool_executor = tick_task_pool_executor || self.thread_num() == 0;
 
                 // we get this from a thread local so we should always be on the scope executors thread.
                 // note: it is possible `scope_executor` and `external_executor` is the same executor,
@@ -414,7 +609,7 @@ impl TaskPool {
                     None
                 };
 
-                match (external_ticker, tick_task_pool_executor) {
+                let finished = match (external_ticker, tick_task_pool_executor) {
                     (Some(external_ticker), true) => {
                         Self::execute_global_external_scope(
                             executor,
@@ -433,7 +628,19 @@ impl TaskPool {
                         Self::execute_global_scope(executor, scope_ticker, get_results).await
                     }
                     (None, false) => Self::execute_scope(scope_ticker, get_results).await,
+                };
+
+                if !finished {
+                    // Cancel the tasks that are left, waiting for running ones to yield, but keep
+                    // the results of those that finished in the meantime
+                    let pending = current.into_inner().into_iter();
+                    for task in pending.chain(iter::from_fn(|| spawned.pop().ok())) {
+                        if let Some(res) = task.cancel().await {
+                            push_result(res);
+                        }
+                    }
                 }
+                (results.into_inner(), !finished)
             })
         }
     }
@@ -443,8 +650,8 @@ impl TaskPool {
         executor: &'scope async_executor::Executor<'scope>,
         external_ticker: ThreadExecutorTicker<'scope, 'ticker>,
         scope_ticker: ThreadExecutorTicker<'scope, 'ticker>,
-        get_results: impl Future<Output = Vec<T>>,
-    ) -> Vec<T> {
+        get_results: impl Future<Output = T>,
+    ) -> T {
         // we restart the executors if a task errors. if a scoped
         // task errors it will panic the scope on the call to get_results
         let execute_forever = async move {
@@ -469,8 +676,8 @@ impl TaskPool {
     async fn execute_external_scope<'scope, 'ticker, T>(
         external_ticker: ThreadExecutorTicker<'scope, 'ticker>,
         scope_ticker: ThreadExecutorTicker<'scope, 'ticker>,
-        get_results: impl Future<Output = Vec<T>>,
-    ) -> Vec<T> {
+        get_results: impl Future<Output = T>,
+    ) -> T {
         let execute_forever = async {
             loop {
                 let tick_forever = async {
@@ -488,8 +695,8 @@ impl TaskPool {
     async fn execute_global_scope<'scope, 'ticker, T>(
         executor: &'scope async_executor::Executor<'scope>,
         scope_ticker: ThreadExecutorTicker<'scope, 'ticker>,
-        get_results: impl Future<Output = Vec<T>>,
-    ) -> Vec<T> {
+        get_results: impl Future<Output = T>,
+    ) -> T {
         let execute_forever = async {
             loop {
                 let tick_forever = async {
@@ -509,8 +716,8 @@ impl TaskPool {
     #[inline]
     async fn execute_scope<'scope, 'ticker, T>(
         scope_ticker: ThreadExecutorTicker<'scope, 'ticker>,
-        get_results: impl Future<Output = Vec<T>>,
-    ) -> Vec<T> {
+        get_results: impl Future<Output = T>,
+    ) -> T {
         let execute_forever = async {
             loop {
                 let tick_forever = async {
@@ -536,7 +743,52 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        let Some(on_thread_panic) = self.config.on_thread_panic.clone() else {
+            return Task::new(self.executor.spawn(future));
+        };
+
+        Task::new(self.executor.spawn(async move {
+            match AssertUnwindSafe(future).catch_unwind().await {
+                Ok(output) => output,
+                Err(payload) => {
+                    on_thread_panic(&*payload);
+                    std::panic::resume_unwind(payload)
+                }
+            }
+        }))
+    }
+
+    /// Spawns a static future onto the thread pool like [`TaskPool::spawn`], but only starts
+    /// running it once a permit has been taken from `semaphore`. The permit is held until the
+    /// future completes, so at most as many futures as the semaphore has permits run at once.
+    ///
+    /// See [`Semaphore`] for how permits are handed out to waiting tasks.
+    pub fn spawn_limited<T>(
+        &self,
+        semaphore: &Semaphore,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        let semaphore = semaphore.clone();
+        self.spawn(async move {
+            let _permit = semaphore.acquire().await;
+            future.await
+        })
+    }
+
+    /// Runs the blocking function `f` on a separate thread and returns a [`Task`] for its result.
+    ///
+    /// Use this for work that blocks instead of awaiting, such as synchronous file or network IO,
+    /// so that it doesn't occupy one of the pool's worker threads. The threads running `f` are
+    /// not part of any [`TaskPool`]; they are shared by all pools, spawned on demand and exit once
+    /// they have been idle for a while, like `tokio::task::spawn_blocking`.
+    pub fn spawn_blocking<T>(&self, f: impl FnOnce() -> T + Send + 'static) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        Task::new(blocking::unblock(f))
     }
 
     /// Spawns a static future on the thread-local async executor for the
@@ -584,11 +836,14 @@ impl Default for TaskPool {
 
 impl Drop for TaskPool {
     fn drop(&mut self) {
-        self.shutdown_tx.close();
+        let workers = mem::take(self.workers.get_mut().unwrap());
+        for worker in &workers {
+            worker.shutdown_tx.close();
+        }
 
         let panicking = thread::panicking();
-        for join_handle in self.threads.drain(..) {
-            let res = join_handle.join();
+        for worker in workers {
+            let res = worker.retire();
             if !panicking {
                 res.expect("Task thread panicked while executing.");
             }
@@ -604,7 +859,7 @@ pub struct Scope<'scope, 'env: 'scope, T> {
     executor: &'scope async_executor::Executor<'scope>,
     external_executor: &'scope ThreadExecutor<'scope>,
     scope_executor: &'scope ThreadExecutor<'scope>,
-    spawned: &'scope ConcurrentQueue<FallibleTask<Result<T, Box<(dyn std::any::Any + Send)>>>>,
+    spawned: &'scope ConcurrentQueue<ScopeTask<T>>,
     // make `Scope` invariant over 'scope and 'env
     scope: PhantomData<&'scope mut &'scope ()>,
     env: PhantomData<&'env mut &'env ()>,
@@ -620,6 +875,33 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
+        let task = self
+            .executor
+            .spawn(AssertUnwindSafe(async move { Some(f.await) }).catch_unwind())
+            .fallible();
+        // ConcurrentQueue only errors when closed or full, but we never
+        // close and use an unbounded queue, so it is safe to unwrap
+        self.spawned.push(task).unwrap();
+    }
+
+    /// Spawns a scoped future onto the thread pool like [`Scope::spawn`], and returns a
+    /// [`CancelHandle`] to stop it early. A cancelled future contributes no result.
+    ///
+    /// ```
+    /// use ens_tasks::{futures_lite::future, TaskPool};
+    ///
+    /// let pool = TaskPool::new();
+    /// let results = pool.scope(|s| {
+    ///     s.spawn(async { 1 });
+    ///     s.spawn_cancellable(future::pending()).cancel();
+    /// });
+    /// assert_eq!(results, [1]);
+    /// ```
+    pub fn spawn_cancellable<Fut: Future<Output = T> + 'scope + Send>(
+        &self,
+        f: Fut,
+    ) -> CancelHandle {
+        let (handle, f) = CancelHandle::wrap(f);
         let task = self
             .executor
             .spawn(AssertUnwindSafe(f).catch_unwind())
@@ -627,6 +909,7 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
         // ConcurrentQueue only errors when closed or full, but we never
         // close and use an unbounded queue, so it is safe to unwrap
         self.spawned.push(task).unwrap();
+        handle
     }
 
     /// Spawns a scoped future onto the thread the scope is run on. The scope *must* outlive
@@ -638,7 +921,7 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     pub fn spawn_on_scope<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
         let task = self
             .scope_executor
-            .spawn(AssertUnwindSafe(f).catch_unwind())
+            .spawn(AssertUnwindSafe(async move { Some(f.await) }).catch_unwind())
             .fallible();
         // ConcurrentQueue only errors when closed or full, but we never
         // close and use an unbounded queue, so it is safe to unwrap
@@ -655,7 +938,7 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     pub fn spawn_on_external<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
         let task = self
             .external_executor
-            .spawn(AssertUnwindSafe(f).catch_unwind())
+            .spawn(AssertUnwindSafe(async move { Some(f.await) }).catch_unwind())
             .fallible();
         // ConcurrentQueue only errors when closed or full, but we never
         // close and use an unbounded queue, so it is safe to unwrap
@@ -682,7 +965,7 @@ mod tests {
     use super::*;
     use std::sync::{
         atomic::{AtomicBool, AtomicI32, Ordering},
-        Barrier,
+        Barrier, Mutex,
     };
 
     #[test]
@@ -726,7 +1009,7 @@ mod tests {
             // Build and immediately drop to terminate
             let _pool = TaskPoolBuilder::new()
                 .num_threads(10)
-                .on_thread_spawn(move || {
+                .on_thread_spawn(move |_| {
                     start_counter.fetch_add(1, Ordering::Relaxed);
                     barrier.clone().wait();
                 })
@@ -753,7 +1036,7 @@ mod tests {
             let last_barrier = barrier.clone();
             let _pool = TaskPoolBuilder::new()
                 .num_threads(5)
-                .on_thread_spawn(move || {
+                .on_thread_spawn(move |_| {
                     start_counter.fetch_add(1, Ordering::Relaxed);
                     barrier.wait();
                 })
@@ -767,6 +1050,26 @@ mod tests {
         assert_eq!(-10, counter.load(Ordering::Relaxed));
     }
 
+    #[test]
+    fn test_thread_spawn_index() {
+        let barrier = Arc::new(Barrier::new(5));
+        let last_barrier = barrier.clone();
+        let indices = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let spawned_indices = indices.clone();
+        let _pool = TaskPoolBuilder::new()
+            .num_threads(4)
+            .on_thread_spawn(move |index| {
+                spawned_indices.lock().unwrap().push(index);
+                barrier.wait();
+            })
+            .build();
+        last_barrier.wait();
+
+        let mut indices = indices.lock().unwrap().clone();
+        indices.sort_unstable();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+    }
+
     #[test]
     fn test_mixed_spawn_on_scope_and_spawn() {
         let pool = TaskPool::new();
@@ -943,4 +1246,225 @@ mod tests {
 
         assert_eq!(count.load(Ordering::Acquire), 1);
     }
+
+    #[test]
+    fn test_yield_now_interleaves_scoped_tasks() {
+        let pool = TaskPool::new();
+        let log = Mutex::new(Vec::new());
+
+        pool.scope(|scope| {
+            for task in 0..2 {
+                let log = &log;
+                scope.spawn_on_scope(async move {
+                    for _ in 0..5 {
+                        log.lock().unwrap().push(task);
+                        crate::yield_now().await;
+                    }
+                });
+            }
+        });
+
+        let log = log.into_inner().unwrap();
+        assert_eq!(log.len(), 10);
+        let last_of_first = log.iter().rposition(|&task| task == 0).unwrap();
+        let first_of_second = log.iter().position(|&task| task == 1).unwrap();
+        assert!(first_of_second < last_of_first);
+    }
+
+    #[test]
+    fn test_spawn_limited_caps_concurrency() {
+        const LIMIT: usize = 4;
+
+        let pool = TaskPoolBuilder::new().num_threads(8).build();
+        let semaphore = Semaphore::new(LIMIT);
+        let running = Arc::new(AtomicI32::new(0));
+        let peak = Arc::new(AtomicI32::new(0));
+
+        let tasks: Vec<_> = (0..32)
+            .map(|_| {
+                let running = running.clone();
+                let peak = peak.clone();
+                pool.spawn_limited(&semaphore, async move {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(std::time::Duration::from_millis(2));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        block_on(crate::join_all(tasks));
+
+        assert!(peak.load(Ordering::SeqCst) <= LIMIT as i32);
+        assert!(peak.load(Ordering::SeqCst) >= 1);
+        assert_eq!(running.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_resize_keeps_running_tasks() {
+        let pool = TaskPoolBuilder::new().num_threads(2).build();
+        let count = Arc::new(AtomicI32::new(0));
+        let spawn_batch = |pool: &TaskPool| {
+            let tasks: Vec<_> = (0..16)
+                .map(|_| {
+                    let count = count.clone();
+                    pool.spawn(async move {
+                        thread::sleep(std::time::Duration::from_millis(1));
+                        count.fetch_add(1, Ordering::SeqCst);
+                    })
+                })
+                .collect();
+            tasks
+        };
+
+        let before_grow = spawn_batch(&pool);
+        pool.resize(6);
+        assert_eq!(pool.thread_num(), 6);
+        block_on(crate::join_all(before_grow));
+        assert_eq!(count.load(Ordering::SeqCst), 16);
+
+        let before_shrink = spawn_batch(&pool);
+        pool.resize(1);
+        assert_eq!(pool.thread_num(), 1);
+        block_on(crate::join_all(before_shrink));
+        assert_eq!(count.load(Ordering::SeqCst), 32);
+
+        let outputs = pool.scope(|scope| {
+            for i in 0..8 {
+                scope.spawn(async move { i });
+            }
+        });
+        assert_eq!(outputs.len(), 8);
+    }
+
+    #[test]
+    fn test_spawn_blocking_leaves_workers_free() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        // Blocks until the task below has run, which can't happen if it takes the only worker
+        let blocked =
+            pool.spawn_blocking(move || rx.recv_timeout(std::time::Duration::from_secs(5)).is_ok());
+        let compute = pool.spawn(async move {
+            tx.send(()).unwrap();
+            7
+        });
+
+        assert_eq!(block_on(compute), 7);
+        assert!(block_on(blocked));
+    }
+
+    #[test]
+    fn test_scope_with_deadline_cancels_slow_tasks() {
+        let pool = TaskPoolBuilder::new().num_threads(2).build();
+        let stopped = AtomicBool::new(false);
+
+        let start = std::time::Instant::now();
+        let (results, exceeded) =
+            pool.scope_with_deadline(std::time::Duration::from_millis(20), |s| {
+                s.spawn(async { 1 });
+                s.spawn(async {
+                    // Sleeps in short polls forever, so it can only end by being cancelled
+                    loop {
+                        thread::sleep(std::time::Duration::from_millis(1));
+                        crate::yield_now().await;
+                        if stopped.load(Ordering::SeqCst) {
+                            return 2;
+                        }
+                    }
+                });
+                s.spawn_cancellable(futures_lite::future::pending())
+                    .cancel();
+            });
+        stopped.store(true, Ordering::SeqCst);
+
+        assert_eq!(results, [1]);
+        assert!(exceeded);
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+
+        let (results, exceeded) =
+            pool.scope_with_deadline(std::time::Duration::from_secs(5), |s| {
+                s.spawn(async { 3 });
+            });
+        assert_eq!(results, [3]);
+        assert!(!exceeded);
+    }
+
+    #[test]
+    fn test_scope_with_deadline_propagates_panics() {
+        let pool = TaskPoolBuilder::new().num_threads(2).build();
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            pool.scope_with_deadline(std::time::Duration::from_millis(20), |s| {
+                s.spawn(futures_lite::future::pending());
+                s.spawn(async { panic!("scoped task failed") });
+            })
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_builder_reads_num_threads_env_var() {
+        fn three(name: &str) -> Option<String> {
+            (name == crate::NUM_THREADS_ENV_VAR).then(|| "3".to_owned())
+        }
+        fn zero(name: &str) -> Option<String> {
+            (name == crate::NUM_THREADS_ENV_VAR).then(|| "0".to_owned())
+        }
+
+        let pool = TaskPoolBuilder::new().env_var(three).build();
+        assert_eq!(pool.thread_num(), 3);
+
+        // an explicit count takes precedence over the environment
+        let pool = TaskPoolBuilder::new().num_threads(2).env_var(three).build();
+        assert_eq!(pool.thread_num(), 2);
+
+        // invalid values are ignored
+        let pool = TaskPoolBuilder::new().env_var(zero).build();
+        assert_eq!(
+            pool.thread_num(),
+            crate::available_parallelism_with(|_| None)
+        );
+    }
+
+    #[test]
+    fn test_parse_num_threads() {
+        assert_eq!(crate::parse_num_threads("3"), Some(3));
+        assert_eq!(crate::parse_num_threads(" 12\n"), Some(12));
+        for invalid in ["0", "-1", "many", ""] {
+            assert_eq!(crate::parse_num_threads(invalid), None);
+        }
+    }
+
+    #[test]
+    fn test_on_thread_panic_sees_task_panics() {
+        let panics = Arc::new(Mutex::new(Vec::new()));
+        let pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .thread_name("Panicky".to_string())
+            .on_thread_panic({
+                let panics = panics.clone();
+                move |payload| {
+                    let message = payload.downcast_ref::<&str>().copied().unwrap_or_default();
+                    let thread = thread::current().name().unwrap_or_default().to_string();
+                    panics.lock().unwrap().push((message, thread));
+                }
+            })
+            .build();
+        assert_eq!(pool.thread_name(), Some("Panicky"));
+
+        pool.spawn(async { panic!("boom") }).detach();
+        for _ in 0..500 {
+            if !panics.lock().unwrap().is_empty() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(2));
+        }
+
+        assert_eq!(
+            *panics.lock().unwrap(),
+            [("boom", "Panicky (0)".to_string())]
+        );
+        // The thread keeps running tasks after the panic
+        assert_eq!(block_on(pool.spawn(async { 1 })), 1);
+    }
 }
@@ -2,10 +2,11 @@
 use crate::AsyncComputeTaskPool;
 #[cfg(feature = "compute_task_pool")]
 use crate::ComputeTaskPool;
-#[cfg(feature = "async_compute_task_pool")]
+#[cfg(feature = "io_task_pool")]
 use crate::IoTaskPool;
 
 use crate::TaskPoolBuilder;
+use std::{any::Any, fmt, sync::Arc};
 
 /// Defines a simple way to determine how many threads to use given the number of remaining cores
 /// and number of total cores
@@ -23,8 +24,9 @@ pub struct TaskPoolThreadAssignmentPolicy {
 impl TaskPoolThreadAssignmentPolicy {
     /// Determine the number of threads to use for this task pool
     fn get_number_of_threads(&self, remaining_threads: usize, total_threads: usize) -> usize {
-        assert!(self.percent >= 0.0);
-        let mut desired = (total_threads as f32 * self.percent).round() as usize;
+        // A negative or NaN percentage is treated as zero
+        let percent = self.percent.max(0.0);
+        let mut desired = (total_threads as f32 * percent).round() as usize;
 
         // Limit ourselves to the number of cores available
         desired = desired.min(remaining_threads);
@@ -32,13 +34,34 @@ impl TaskPoolThreadAssignmentPolicy {
         // Clamp by min_threads, max_threads. (This may result in us using more threads than are
         // available, this is intended. An example case where this might happen is a device with
         // <= 2 threads.
-        desired.clamp(self.min_threads, self.max_threads)
+        // Unlike `clamp` this does not panic if `min_threads` is greater than `max_threads`.
+        desired.max(self.min_threads).min(self.max_threads)
+    }
+
+    /// Determine the number of threads to use for the task pool named `pool_name`, ensuring
+    /// that at least one thread is used.
+    ///
+    /// A pool without threads would never make progress, so a warning is logged and the count is
+    /// clamped to one if the policy computes zero threads.
+    fn get_validated_number_of_threads(
+        &self,
+        remaining_threads: usize,
+        total_threads: usize,
+        pool_name: &str,
+    ) -> usize {
+        let threads = self.get_number_of_threads(remaining_threads, total_threads);
+        if threads == 0 {
+            log::warn!("{pool_name} was configured with zero threads, using one thread instead");
+            return 1;
+        }
+
+        threads
     }
 }
 
 /// Helper for configuring and creating the default task pools. For end-users who want full control,
 /// set up [`TaskPoolPlugin`](super::TaskPoolPlugin)
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct TaskPoolOptions {
     /// If the number of physical cores is less than min_total_threads, force using
     /// min_total_threads
@@ -53,6 +76,23 @@ pub struct TaskPoolOptions {
     pub async_compute: TaskPoolThreadAssignmentPolicy,
     /// Used to determine number of compute threads to allocate
     pub compute: TaskPoolThreadAssignmentPolicy,
+
+    /// Called when a task of one of the default pools panics, see
+    /// [`TaskPoolBuilder::on_thread_panic`]
+    pub on_thread_panic: Option<Arc<dyn Fn(&(dyn Any + Send)) + Send + Sync + 'static>>,
+}
+
+impl fmt::Debug for TaskPoolOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskPoolOptions")
+            .field("min_total_threads", &self.min_total_threads)
+            .field("max_total_threads", &self.max_total_threads)
+            .field("io", &self.io)
+            .field("async_compute", &self.async_compute)
+            .field("compute", &self.compute)
+            .field("on_thread_panic", &self.on_thread_panic.is_some())
+            .finish()
+    }
 }
 
 impl Default for TaskPoolOptions {
@@ -82,6 +122,8 @@ impl Default for TaskPoolOptions {
                 max_threads: usize::MAX,
                 percent: 1.0, // This 1.0 here means "whatever is left over"
             },
+
+            on_thread_panic: None,
         }
     }
 }
@@ -96,43 +138,62 @@ impl TaskPoolOptions {
         }
     }
 
+    /// Creates the builder of one of the default pools.
+    #[cfg(any(
+        feature = "io_task_pool",
+        feature = "async_compute_task_pool",
+        feature = "compute_task_pool"
+    ))]
+    fn pool_builder(&self, num_threads: usize, thread_name: &str) -> TaskPoolBuilder {
+        let builder = TaskPoolBuilder::default()
+            .num_threads(num_threads)
+            .thread_name(thread_name.to_string());
+        match self.on_thread_panic.clone() {
+            Some(on_thread_panic) => {
+                builder.on_thread_panic(move |payload| on_thread_panic(payload))
+            }
+            None => builder,
+        }
+    }
+
     /// Inserts the default thread pools into the given resource map based on the configured values
     pub fn create_default_pools(&self) {
-        let total_threads =
-            crate::available_parallelism().clamp(self.min_total_threads, self.max_total_threads);
+        // Unlike `clamp` this does not panic if `min_total_threads` is greater than
+        // `max_total_threads`.
+        let total_threads = crate::available_parallelism()
+            .max(self.min_total_threads)
+            .min(self.max_total_threads)
+            .max(1);
 
         let mut remaining_threads = total_threads;
 
         #[cfg(feature = "io_task_pool")]
         {
             // Determine the number of IO threads we will use
-            let io_threads = self
-                .io
-                .get_number_of_threads(remaining_threads, total_threads);
+            let io_threads = self.io.get_validated_number_of_threads(
+                remaining_threads,
+                total_threads,
+                "IO Task Pool",
+            );
 
             remaining_threads = remaining_threads.saturating_sub(io_threads);
 
-            IoTaskPool::get_or_init(|| {
-                TaskPoolBuilder::default()
-                    .num_threads(io_threads)
-                    .thread_name("IO Task Pool".to_string())
-                    .build()
-            });
+            IoTaskPool::get_or_init(|| self.pool_builder(io_threads, "IO Task Pool").build());
         }
 
         #[cfg(feature = "async_compute_task_pool")]
         {
             // Determine the number of async compute threads we will use
-            let async_compute_threads = self
-                .async_compute
-                .get_number_of_threads(remaining_threads, total_threads);
+            let async_compute_threads = self.async_compute.get_validated_number_of_threads(
+                remaining_threads,
+                total_threads,
+                "Async Compute Task Pool",
+            );
 
             remaining_threads = remaining_threads.saturating_sub(async_compute_threads);
 
             AsyncComputeTaskPool::get_or_init(|| {
-                TaskPoolBuilder::default()
-                    .num_threads(async_compute_threads)
-                    .thread_name("Async Compute Task Pool".to_string())
+                self.pool_builder(async_compute_threads, "Async Compute Task Pool")
                     .build()
             });
         }
@@ -141,16 +202,54 @@ impl TaskPoolOptions {
         {
             // Determine the number of compute threads we will use
             // This is intentionally last so that an end user can specify 1.0 as the percent
-            let compute_threads = self
-                .compute
-                .get_number_of_threads(remaining_threads, total_threads);
+            let compute_threads = self.compute.get_validated_number_of_threads(
+                remaining_threads,
+                total_threads,
+                "Compute Task Pool",
+            );
 
             ComputeTaskPool::get_or_init(|| {
-                TaskPoolBuilder::default()
-                    .num_threads(compute_threads)
-                    .thread_name("Compute Task Pool".to_string())
+                self.pool_builder(compute_threads, "Compute Task Pool")
                     .build()
             });
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_threads_are_clamped_to_one() {
+        let policy = TaskPoolThreadAssignmentPolicy {
+            min_threads: 0,
+            max_threads: 0,
+            percent: 0.0,
+        };
+
+        assert_eq!(policy.get_number_of_threads(4, 4), 0);
+        assert_eq!(
+            policy.get_validated_number_of_threads(4, 4, "Test Task Pool"),
+            1
+        );
+        assert_eq!(
+            policy.get_validated_number_of_threads(0, 0, "Test Task Pool"),
+            1
+        );
+    }
+
+    #[test]
+    fn degenerate_policy_does_not_panic() {
+        let policy = TaskPoolThreadAssignmentPolicy {
+            min_threads: 4,
+            max_threads: 2,
+            percent: -1.0,
+        };
+
+        assert_eq!(
+            policy.get_validated_number_of_threads(8, 8, "Test Task Pool"),
+            2
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{Real, Time, Timer, TimerMode};
-use ens::system::Res;
+use ens::access::Res;
 use std::time::Duration;
 
 /// Run condition that is active on a regular time interval, using [`Time`] to advance
@@ -8,7 +8,7 @@ use std::time::Duration;
 /// ```no_run
 /// # use ens_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
 /// # use ens::schedule::IntoSystemConfigs;
-/// # use ens_utils::Duration;
+/// # use std::time::Duration;
 /// # use ens_time::common_conditions::on_timer;
 /// fn main() {
 ///     App::new()
@@ -48,7 +48,7 @@ pub fn on_timer(duration: Duration) -> impl FnMut(Res<Time>) -> bool + Clone {
 /// ```no_run
 /// # use ens_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
 /// # use ens::schedule::IntoSystemConfigs;
-/// # use ens_utils::Duration;
+/// # use std::time::Duration;
 /// # use ens_time::common_conditions::on_real_timer;
 /// fn main() {
 ///     App::new()
@@ -83,12 +83,12 @@ pub fn on_real_timer(duration: Duration) -> impl FnMut(Res<Time<Real>>) -> bool
 
 /// Run condition that is active *once* after the specified delay,
 /// using [`Time`] to advance the timer.
-/// The timer ticks at the rate of [`Time::relative_speed`].
+/// The timer ticks at the [relative speed](Time::relative_speed) of [`Time<Virtual>`](crate::Virtual).
 ///
 /// ```rust,no_run
 /// # use ens_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
 /// # use ens::schedule::IntoSystemConfigs;
-/// # use ens_utils::Duration;
+/// # use std::time::Duration;
 /// # use ens_time::common_conditions::once_after_delay;
 /// fn main() {
 ///     App::new()
@@ -118,14 +118,14 @@ pub fn once_after_delay(duration: Duration) -> impl FnMut(Res<Time>) -> bool + C
 /// ```rust,no_run
 /// # use ens_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
 /// # use ens::schedule::IntoSystemConfigs;
-/// # use ens_utils::Duration;
-/// # use ens_time::common_conditions::once_after_delay;
+/// # use std::time::Duration;
+/// # use ens_time::common_conditions::once_after_real_delay;
 /// fn main() {
 ///     App::new()
 ///         .add_plugins(DefaultPlugins)
 ///         .add_systems(
 ///             Update,
-///             tick.run_if(once_after_delay(Duration::from_secs(1))),
+///             tick.run_if(once_after_real_delay(Duration::from_secs(1))),
 ///         )
 ///     .run();
 /// }
@@ -143,12 +143,12 @@ pub fn once_after_real_delay(duration: Duration) -> impl FnMut(Res<Time<Real>>)
 
 /// Run condition that is active *indefinitely* after the specified delay,
 /// using [`Time`] to advance the timer.
-/// The timer ticks at the rate of [`Time::relative_speed`].
+/// The timer ticks at the [relative speed](Time::relative_speed) of [`Time<Virtual>`](crate::Virtual).
 ///
 /// ```rust,no_run
 /// # use ens_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
 /// # use ens::schedule::IntoSystemConfigs;
-/// # use ens_utils::Duration;
+/// # use std::time::Duration;
 /// # use ens_time::common_conditions::repeating_after_delay;
 /// fn main() {
 ///     App::new()
@@ -178,7 +178,7 @@ pub fn repeating_after_delay(duration: Duration) -> impl FnMut(Res<Time>) -> boo
 /// ```rust,no_run
 /// # use ens_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
 /// # use ens::schedule::IntoSystemConfigs;
-/// # use ens_utils::Duration;
+/// # use std::time::Duration;
 /// # use ens_time::common_conditions::repeating_after_real_delay;
 /// fn main() {
 ///     App::new()
@@ -206,11 +206,58 @@ pub fn repeating_after_real_delay(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ens::schedule::{IntoSystemConfigs, Schedule};
+    use crate::{TimePlugin, TimeUpdateStrategy};
+    use ens::{
+        access::ResMut,
+        schedule::{IntoSystemConfigs, Schedule},
+        system::Resource,
+    };
+    use ens_app::{App, Update};
     use std::time::Duration;
 
     fn test_system() {}
 
+    #[derive(Resource, Default)]
+    struct Fired {
+        update: usize,
+        at: Vec<usize>,
+    }
+
+    fn record(mut fired: ResMut<Fired>) {
+        let update = fired.update;
+        fired.at.push(update);
+    }
+
+    /// Runs 7 updates advancing by 250ms each, except for the very first update which has no
+    /// previous instant, and returns the indices of the updates `record` ran on.
+    fn fired_at<M>(condition: impl ens::schedule::Condition<M>) -> Vec<usize> {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )))
+            .init_resource::<Fired>()
+            .add_systems(Update, record.run_if(condition));
+
+        for update in 0..7 {
+            app.world.resource_mut::<Fired>().update = update;
+            app.update();
+        }
+        std::mem::take(&mut app.world.resource_mut::<Fired>().at)
+    }
+
+    #[test]
+    fn on_timer_fires_every_period() {
+        // The elapsed time equals the 500ms period exactly on updates 2, 4 and 6
+        assert_eq!(fired_at(on_timer(Duration::from_millis(500))), [2, 4, 6]);
+    }
+
+    #[test]
+    fn once_after_delay_fires_once() {
+        assert_eq!(fired_at(once_after_delay(Duration::from_millis(500))), [2]);
+        assert_eq!(fired_at(once_after_delay(Duration::from_millis(600))), [3]);
+    }
+
     // Ensure distributive_run_if compiles with the common conditions.
     #[test]
     fn distributive_run_if_compiles() {
@@ -3,17 +3,23 @@
 /// Common run conditions
 #[cfg(feature = "common_conditions")]
 pub mod common_conditions;
+mod fixed;
+mod lint;
 mod real;
 #[allow(clippy::module_inception)]
 mod time;
+mod virt;
 
 #[cfg(feature = "timers")]
 mod stopwatch;
 #[cfg(feature = "timers")]
 mod timer;
 
+pub use fixed::*;
+pub use lint::*;
 pub use real::*;
 pub use time::*;
+pub use virt::*;
 
 #[cfg(feature = "timers")]
 pub use stopwatch::*;
@@ -23,7 +29,7 @@ pub use timer::*;
 pub mod prelude {
     //! The Bevy Time Prelude.
     #[doc(hidden)]
-    pub use crate::{Real, Time};
+    pub use crate::{Fixed, Real, Time, Virtual};
 
     #[cfg(feature = "timers")]
     pub use crate::{Stopwatch, Timer, TimerMode};
@@ -32,10 +38,18 @@ pub mod prelude {
 #[cfg(feature = "events")]
 use ens::event::{signal_event_update_system, EventUpdateSignal, EventUpdates};
 use ens::prelude::*;
-use ens_app::{prelude::*, PreUpdate};
-use std::time::{Duration, Instant};
+use ens_app::{prelude::*, PreUpdate, RunFixedUpdateLoop};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
 
 /// Adds time functionality to Apps.
+///
+/// Besides [`Time<Real>`], this adds [`Time<Virtual>`], which can be paused and scaled, the
+/// generic [`Time`] clock, which follows [`Time<Virtual>`] except during
+/// [`FixedUpdate`](ens_app::FixedUpdate), and [`Time<Fixed>`], which drives
+/// [`FixedUpdate`](ens_app::FixedUpdate) from [`RunFixedUpdateLoop`].
 #[derive(Default)]
 pub struct TimePlugin;
 
@@ -46,9 +60,13 @@ pub struct TimeSystem;
 
 impl Plugin for TimePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Time<Real>>()
+        app.init_resource::<Time>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<Time<Virtual>>()
+            .init_resource::<Time<Fixed>>()
             .init_resource::<TimeUpdateStrategy>()
-            .add_systems(PreUpdate, time_system.in_set(TimeSystem));
+            .add_systems(PreUpdate, time_system.in_set(TimeSystem))
+            .add_systems(RunFixedUpdateLoop, run_fixed_update_schedule);
     }
 }
 
@@ -68,22 +86,50 @@ pub enum TimeUpdateStrategy {
     ManualInstant(Instant),
     /// [`Time`] will be incremented by the specified [`Duration`] each frame.
     ManualDuration(Duration),
+    /// [`Time`] will be incremented by the front [`Duration`] of the queue each frame, which is
+    /// then popped. Once the queue is empty, time no longer advances.
+    ///
+    /// This is useful to replay the exact frame deltas of a recorded session. Unlike the other
+    /// manual strategies, the very first frame advances by its delta as well.
+    ManualAdvance(VecDeque<Duration>),
 }
 
 /// The system used to update the [`Time`] used by app logic.
-fn time_system(mut time: ResMut<Time<Real>>, update_strategy: Res<TimeUpdateStrategy>) {
-    match update_strategy.as_ref() {
-        TimeUpdateStrategy::Automatic => time.update_with_instant(Instant::now()),
-        TimeUpdateStrategy::ManualInstant(instant) => time.update_with_instant(*instant),
-        TimeUpdateStrategy::ManualDuration(duration) => time.update_with_duration(*duration),
+fn time_system(
+    mut real_time: ResMut<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut time: ResMut<Time>,
+    mut update_strategy: ResMut<TimeUpdateStrategy>,
+) {
+    match &mut *update_strategy {
+        TimeUpdateStrategy::Automatic => real_time.update_with_instant(Instant::now()),
+        TimeUpdateStrategy::ManualInstant(instant) => real_time.update_with_instant(*instant),
+        TimeUpdateStrategy::ManualDuration(duration) => real_time.update_with_duration(*duration),
+        TimeUpdateStrategy::ManualAdvance(deltas) => {
+            if real_time.last_update().is_none() {
+                // Start from the startup instant so the first delta isn't swallowed
+                let startup = real_time.startup();
+                real_time.update_with_instant(startup);
+            }
+            real_time.update_with_duration(deltas.pop_front().unwrap_or(Duration::ZERO));
+        }
     }
+    virtual_time.advance_with_real(&real_time);
+    *time = virtual_time.as_generic();
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Real, Time, TimePlugin, TimeUpdateStrategy};
-    use ens::event::{Event, EventReader, EventWriter};
-    use ens_app::{App, Startup, Update};
+    use crate::{
+        Fixed, Real, Time, TimeOrderingLintExt, TimePlugin, TimeSystem, TimeUpdateStrategy, Virtual,
+    };
+    use ens::{
+        access::{Res, ResMut},
+        event::{Event, EventReader, EventWriter},
+        schedule::IntoSystemConfigs,
+        system::Resource,
+    };
+    use ens_app::{App, FixedUpdate, PreUpdate, Startup, Update};
     use std::error::Error;
     use std::time::Duration;
 
@@ -140,4 +186,117 @@ mod tests {
         // Check event type 2 has been dropped
         rx2.try_recv()
     }
+
+    #[test]
+    fn manual_advance_replays_queued_deltas() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualAdvance(
+                [16, 33, 16].map(Duration::from_millis).into(),
+            ));
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let real = app.world.resource::<Time<Real>>();
+        assert_eq!(real.elapsed(), Duration::from_millis(65));
+        assert_eq!(real.delta(), Duration::from_millis(16));
+
+        // An empty queue stops the clock instead of panicking
+        app.update();
+        let time = app.world.resource::<Time>();
+        assert_eq!(time.elapsed(), Duration::from_millis(65));
+        assert_eq!(time.delta(), Duration::ZERO);
+    }
+
+    #[test]
+    fn lint_time_ordering_flags_unordered_readers() {
+        fn unordered_reader(_time: Res<Time<Real>>) {}
+        fn ordered_reader(_time: Res<Time<Real>>) {}
+        fn late_reader(_time: Res<Time<Real>>) {}
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .add_systems(
+                PreUpdate,
+                (unordered_reader, ordered_reader.after(TimeSystem)),
+            )
+            .add_systems(Update, late_reader);
+
+        let offenders = app.lint_time_ordering();
+        assert_eq!(offenders.len(), 1);
+        assert!(offenders[0].ends_with("unordered_reader"));
+    }
+
+    #[test]
+    fn fixed_update_runs_per_accumulated_timestep() {
+        #[derive(Resource, Default)]
+        struct FixedRuns {
+            count: u32,
+            delta: Duration,
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<FixedRuns>()
+            .insert_resource(Time::<Fixed>::from_hz(64.0))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                1. / 30.,
+            )))
+            .add_systems(
+                FixedUpdate,
+                |time: Res<Time>, mut runs: ResMut<FixedRuns>| {
+                    runs.count += 1;
+                    runs.delta = time.delta();
+                },
+            );
+
+        // The first update has no previous instant, so no time elapses
+        app.update();
+        assert_eq!(app.world.resource::<FixedRuns>().count, 0);
+
+        app.update();
+        let runs = app.world.resource::<FixedRuns>();
+        assert_eq!(runs.count, 2);
+        assert_eq!(runs.delta, Duration::from_secs_f64(1. / 64.));
+
+        let fixed = app.world.resource::<Time<Fixed>>();
+        assert_eq!(
+            fixed.overstep(),
+            Duration::from_secs_f64(1. / 30.) - 2 * Duration::from_secs_f64(1. / 64.)
+        );
+        // Outside of `FixedUpdate`, the default clock follows virtual time again
+        assert_eq!(
+            app.world.resource::<Time>().delta(),
+            Duration::from_secs_f64(1. / 30.)
+        );
+    }
+
+    #[test]
+    fn virtual_time_scales_and_pauses() {
+        let frame = Duration::from_millis(10);
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(frame));
+        app.update();
+
+        app.world
+            .resource_mut::<Time<Virtual>>()
+            .set_relative_speed(2.0);
+        app.update();
+        assert_eq!(app.world.resource::<Time<Virtual>>().delta(), 2 * frame);
+        assert_eq!(app.world.resource::<Time>().delta(), 2 * frame);
+        assert_eq!(app.world.resource::<Time<Real>>().delta(), frame);
+
+        app.world.resource_mut::<Time<Virtual>>().pause();
+        let elapsed = app.world.resource::<Time>().elapsed();
+        app.update();
+        app.update();
+        assert_eq!(app.world.resource::<Time>().elapsed(), elapsed);
+        assert_eq!(app.world.resource::<Time>().delta(), Duration::ZERO);
+
+        app.world.resource_mut::<Time<Virtual>>().unpause();
+        app.update();
+        assert_eq!(app.world.resource::<Time>().elapsed(), elapsed + 2 * frame);
+    }
 }
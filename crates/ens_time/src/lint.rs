@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+
+use ens::schedule::{Schedules, SystemSet};
+use ens_app::App;
+
+use crate::{Real, Time, TimeSystem, Virtual};
+
+/// An extension trait for [`App`] that checks systems reading [`Time`] are ordered after
+/// [`TimeSystem`].
+pub trait TimeOrderingLintExt {
+    /// Warns about every system that reads [`Time`], [`Time<Real>`] or [`Time<Virtual>`] in the
+    /// same schedule as [`TimeSystem`] without being ordered after it, and returns their names.
+    ///
+    /// Such systems may run before the clock is advanced and observe the previous frame's time.
+    /// Systems in other schedules are not checked, since schedules run one after another.
+    ///
+    /// This is a development aid and is never run automatically. It builds the schedules it
+    /// inspects, so call it once all systems have been added, e.g. right before [`App::run`].
+    /// Systems added afterwards are not checked.
+    fn lint_time_ordering(&mut self) -> Vec<Cow<'static, str>>;
+}
+
+impl TimeOrderingLintExt for App {
+    fn lint_time_ordering(&mut self) -> Vec<Cow<'static, str>> {
+        let components = self.world().components();
+        let time_ids: Vec<_> = [
+            components.resource_id::<Time>(),
+            components.resource_id::<Time<Real>>(),
+            components.resource_id::<Time<Virtual>>(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if time_ids.is_empty() {
+            return Vec::new();
+        }
+
+        let labels: Vec<_> = self
+            .world()
+            .resource::<Schedules>()
+            .iter()
+            .filter(|(_, schedule)| {
+                schedule
+                    .graph()
+                    .system_sets()
+                    .any(|(_, set, ..)| set == &TimeSystem as &dyn SystemSet)
+            })
+            .map(|(_, schedule)| schedule.label())
+            .collect();
+
+        let mut offenders = Vec::new();
+        for label in labels {
+            self.world_mut().schedule_scope(label, |world, schedule| {
+                if let Err(err) = schedule.initialize(world) {
+                    log::error!("failed to build schedule {label:?} for linting: {err}");
+                    return;
+                }
+
+                let (Ok(in_set), Ok(after)) = (
+                    schedule.systems_in_set(TimeSystem),
+                    schedule.systems_ordered_after(TimeSystem),
+                ) else {
+                    return;
+                };
+
+                for (node_id, system) in schedule.systems().into_iter().flatten() {
+                    if in_set.contains(&node_id) || after.contains(&node_id) {
+                        continue;
+                    }
+                    let access = system.component_access();
+                    if !time_ids.iter().any(|id| access.has_read(*id)) {
+                        continue;
+                    }
+
+                    let name = system.name();
+                    log::warn!(
+                        "system `{name}` in schedule {label:?} reads `Time` but is not ordered \
+                        after `TimeSystem`, so it may observe the previous frame's time; \
+                        add `.after(TimeSystem)` to it"
+                    );
+                    offenders.push(name);
+                }
+            });
+        }
+
+        offenders
+    }
+}
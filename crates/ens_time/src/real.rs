@@ -27,11 +27,16 @@ use crate::time::Time;
 /// [`Instant`]s for [`startup()`](Time::startup),
 /// [`first_update()`](Time::first_update) and
 /// [`last_update()`](Time::last_update) are recorded and accessible.
+///
+/// The clock also keeps an exponential moving average of the frame rate, available through
+/// [`smoothed_fps()`](Time::smoothed_fps), next to the instantaneous [`fps()`](Time::fps).
 #[derive(Debug, Copy, Clone)]
 pub struct Real {
     startup: Instant,
     first_update: Option<Instant>,
     last_update: Option<Instant>,
+    smoothed_fps: f32,
+    fps_smoothing: f32,
 }
 
 impl Default for Real {
@@ -40,10 +45,17 @@ impl Default for Real {
             startup: Instant::now(),
             first_update: None,
             last_update: None,
+            smoothed_fps: 0.0,
+            fps_smoothing: Self::DEFAULT_FPS_SMOOTHING,
         }
     }
 }
 
+impl Real {
+    /// The default weight given to the newest frame in [`smoothed_fps()`](Time::smoothed_fps).
+    pub const DEFAULT_FPS_SMOOTHING: f32 = 0.1;
+}
+
 impl Time<Real> {
     /// Constructs a new `Time<Real>` instance with a specific startup
     /// [`Instant`].
@@ -92,6 +104,48 @@ impl Time<Real> {
         let delta = instant - last_update;
         self.advance_by(delta);
         self.context_mut().last_update = Some(instant);
+
+        let fps = self.fps();
+        if fps > 0.0 {
+            let context = self.context_mut();
+            context.smoothed_fps = if context.smoothed_fps == 0.0 {
+                fps
+            } else {
+                context.smoothed_fps + context.fps_smoothing * (fps - context.smoothed_fps)
+            };
+        }
+    }
+
+    /// Returns the frame rate as an exponential moving average over past updates.
+    ///
+    /// Unlike [`fps()`](Time::fps), which only looks at the last delta and can fluctuate wildly,
+    /// each update moves this value towards the instantaneous frame rate by the
+    /// [`fps_smoothing()`](Time::fps_smoothing) factor. Updates with a zero delta are ignored, and
+    /// the value is `0.0` until the first non-zero delta.
+    #[inline]
+    pub fn smoothed_fps(&self) -> f32 {
+        self.context().smoothed_fps
+    }
+
+    /// Returns the weight given to the newest frame in [`smoothed_fps()`](Time::smoothed_fps).
+    #[inline]
+    pub fn fps_smoothing(&self) -> f32 {
+        self.context().fps_smoothing
+    }
+
+    /// Sets the weight given to the newest frame in [`smoothed_fps()`](Time::smoothed_fps).
+    ///
+    /// Values closer to `1.0` react faster to changes, values closer to `0.0` are steadier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `smoothing` is not within `(0.0, 1.0]`.
+    pub fn set_fps_smoothing(&mut self, smoothing: f32) {
+        assert!(
+            smoothing > 0.0 && smoothing <= 1.0,
+            "tried to set fps smoothing outside of (0.0, 1.0]"
+        );
+        self.context_mut().fps_smoothing = smoothing;
     }
 
     /// Returns the [`Instant`] the clock was created.
@@ -189,6 +243,27 @@ mod test {
         assert_eq!(time.elapsed(), third_update - first_update);
     }
 
+    #[test]
+    fn test_smoothed_fps() {
+        let mut time = Time::<Real>::new(Instant::now());
+        time.set_fps_smoothing(0.5);
+
+        time.update_with_duration(Duration::from_millis(100));
+        assert_eq!(time.smoothed_fps(), 0.0);
+
+        time.update_with_duration(Duration::from_millis(100));
+        assert_eq!(time.fps(), 10.0);
+        assert_eq!(time.smoothed_fps(), 10.0);
+
+        time.update_with_duration(Duration::from_millis(50));
+        assert_eq!(time.fps(), 20.0);
+        assert_eq!(time.smoothed_fps(), 15.0);
+
+        time.update_with_duration(Duration::ZERO);
+        assert_eq!(time.fps(), 0.0);
+        assert_eq!(time.smoothed_fps(), 15.0);
+    }
+
     #[test]
     fn test_update_with_duration() {
         let startup = Instant::now();
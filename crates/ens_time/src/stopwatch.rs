@@ -2,6 +2,9 @@ use std::time::Duration;
 
 /// A Stopwatch is a struct that track elapsed time when started.
 ///
+/// Two stopwatches are equal if they have the same elapsed time and paused state; recorded
+/// [`splits`](Stopwatch::splits) are not compared.
+///
 /// # Examples
 ///
 /// ```
@@ -21,13 +24,26 @@ use std::time::Duration;
 /// assert!(stopwatch.paused());
 /// assert_eq!(stopwatch.elapsed_secs(), 0.0);
 /// ```
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
 pub struct Stopwatch {
     elapsed: Duration,
     paused: bool,
+    /// The elapsed time at the last [`split`](Stopwatch::split).
+    #[cfg_attr(feature = "serialize", serde(default))]
+    last_split: Duration,
+    #[cfg_attr(feature = "serialize", serde(default))]
+    splits: Vec<Duration>,
 }
 
+impl PartialEq for Stopwatch {
+    fn eq(&self, other: &Self) -> bool {
+        self.elapsed == other.elapsed && self.paused == other.paused
+    }
+}
+
+impl Eq for Stopwatch {}
+
 impl Stopwatch {
     /// Create a new unpaused `Stopwatch` with no elapsed time.
     ///
@@ -115,6 +131,9 @@ impl Stopwatch {
     /// If the stopwatch is paused, ticking will not have any effect
     /// on elapsed time.
     ///
+    /// This matches the interface of [`Timer::tick`](crate::Timer::tick), so stopwatches and
+    /// timers can be driven identically from a [`Time`](crate::Time) delta.
+    ///
     /// # Examples
     /// ```
     /// # use ens_time::*;
@@ -184,8 +203,49 @@ impl Stopwatch {
         self.paused
     }
 
+    /// Records a split and returns the time elapsed since the previous split, or since the last
+    /// [`reset`](Stopwatch::reset) for the first one.
+    ///
+    /// Splits don't affect [`elapsed`](Stopwatch::elapsed), and they add up to it unless the
+    /// elapsed time was changed with [`set_elapsed`](Stopwatch::set_elapsed). Nothing is
+    /// allocated until the first split.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ens_time::*;
+    /// use std::time::Duration;
+    /// let mut stopwatch = Stopwatch::new();
+    /// stopwatch.tick(Duration::from_secs(2));
+    /// assert_eq!(stopwatch.split(), Duration::from_secs(2));
+    /// stopwatch.tick(Duration::from_secs(1));
+    /// assert_eq!(stopwatch.split(), Duration::from_secs(1));
+    /// assert_eq!(stopwatch.splits(), &[Duration::from_secs(2), Duration::from_secs(1)]);
+    /// assert_eq!(stopwatch.elapsed(), Duration::from_secs(3));
+    /// ```
+    pub fn split(&mut self) -> Duration {
+        let split = self.elapsed.saturating_sub(self.last_split);
+        self.last_split = self.elapsed;
+        self.splits.push(split);
+        split
+    }
+
+    /// Returns the splits recorded by [`split`](Stopwatch::split), oldest first.
+    #[inline]
+    pub fn splits(&self) -> &[Duration] {
+        &self.splits
+    }
+
+    /// Forgets the recorded splits. The next [`split`](Stopwatch::split) still measures from the
+    /// previous one.
+    #[inline]
+    pub fn clear_splits(&mut self) {
+        self.splits.clear();
+    }
+
     /// Resets the stopwatch. The reset doesn't affect the paused state of the stopwatch.
     ///
+    /// Recorded [`splits`](Stopwatch::splits) are cleared as well.
+    ///
     /// # Examples
     /// ```
     /// # use ens_time::*;
@@ -198,5 +258,62 @@ impl Stopwatch {
     #[inline]
     pub fn reset(&mut self) {
         self.elapsed = Default::default();
+        self.last_split = Default::default();
+        self.splits.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Timer, TimerMode};
+
+    #[test]
+    fn ticks_like_timer() {
+        let mut stopwatch = Stopwatch::new();
+        let mut timer = Timer::from_seconds(10.0, TimerMode::Once);
+        let delta = Duration::from_millis(250);
+
+        for _ in 0..4 {
+            stopwatch.tick(delta);
+            timer.tick(delta);
+        }
+        assert_eq!(stopwatch.elapsed(), timer.elapsed());
+
+        stopwatch.pause();
+        timer.pause();
+        stopwatch.tick(delta);
+        timer.tick(delta);
+        assert_eq!(stopwatch.elapsed(), Duration::from_secs(1));
+        assert_eq!(stopwatch.elapsed(), timer.elapsed());
+    }
+
+    #[test]
+    fn splits_sum_to_elapsed() {
+        let mut stopwatch = Stopwatch::new();
+        let mut splits = Vec::new();
+
+        for millis in [100, 250, 400] {
+            stopwatch.tick(Duration::from_millis(millis));
+            splits.push(stopwatch.split());
+        }
+
+        assert_eq!(splits, [100, 250, 400].map(Duration::from_millis).to_vec());
+        assert_eq!(stopwatch.splits(), splits);
+        assert_eq!(
+            stopwatch.splits().iter().sum::<Duration>(),
+            stopwatch.elapsed()
+        );
+
+        let mut other = stopwatch.clone();
+        other.clear_splits();
+        assert_eq!(stopwatch, other);
+
+        stopwatch.clear_splits();
+        assert!(stopwatch.splits().is_empty());
+        assert_eq!(stopwatch.elapsed(), Duration::from_millis(750));
+        stopwatch.reset();
+        stopwatch.tick(Duration::from_millis(50));
+        assert_eq!(stopwatch.split(), Duration::from_millis(50));
     }
 }
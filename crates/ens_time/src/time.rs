@@ -8,9 +8,11 @@ use std::time::Duration;
 /// [`TimePlugin`](crate::TimePlugin):
 ///
 /// - [`Time<Real>`](crate::real::Real) tracks real wall-clock time elapsed.
+/// - [`Time<Virtual>`](crate::virt::Virtual) tracks virtual game time that may
+///   be paused or scaled.
 /// - [`Time`] is a generic clock that corresponds to "current" or "default"
 ///   time for systems. It contains [`Time<Virtual>`](crate::virt::Virtual)
-///   except inside the [`FixedMain`](ens_app::FixedMain) schedule when it
+///   except inside the [`FixedUpdate`](ens_app::FixedUpdate) schedule when it
 ///   contains [`Time<Fixed>`](crate::fixed::Fixed).
 ///
 /// The time elapsed since the previous time this clock was advanced is saved as
@@ -40,7 +42,7 @@ use std::time::Duration;
 /// [`elapsed()`](Time::elapsed) should use `Res<Time>` to access the default
 /// time configured for the program. By default, this refers to
 /// [`Time<Virtual>`](crate::virt::Virtual) except during the
-/// [`FixedMain`](ens_app::FixedMain) schedule when it refers to
+/// [`FixedUpdate`](ens_app::FixedUpdate) schedule when it refers to
 /// [`Time<Fixed>`](crate::fixed::Fixed). This ensures your system can be used
 /// either in [`Update`](ens_app::Update) or
 /// [`FixedUpdate`](ens_app::FixedUpdate) schedule depending on what is needed.
@@ -90,7 +92,7 @@ use std::time::Duration;
 /// get a "generic" snapshot of your clock by calling `as_generic()` and then
 /// overwrite the [`Time`] resource with it. The default systems added by
 /// [`TimePlugin`](crate::TimePlugin) will overwrite the [`Time`] clock during
-/// [`First`](ens_app::First) and [`FixedUpdate`](ens_app::FixedUpdate)
+/// [`PreUpdate`](ens_app::PreUpdate) and [`FixedUpdate`](ens_app::FixedUpdate)
 /// schedules.
 ///
 /// ```
@@ -200,14 +202,59 @@ impl<T: Default> Time<T> {
         self.delta
     }
 
+    /// Returns how much time has advanced since the last [`update`](#method.update), as [`f32`]
+    /// seconds.
+    #[inline]
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    /// Returns how much time has advanced since the last [`update`](#method.update), as [`f64`]
+    /// seconds.
+    #[inline]
+    pub fn delta_seconds_f64(&self) -> f64 {
+        self.delta.as_secs_f64()
+    }
+
+    /// Returns the instantaneous frame rate, computed from the last [`delta`](#method.delta) alone.
+    ///
+    /// Returns `0.0` if the delta is zero, such as on the first update. The value can jump around
+    /// from frame to frame; for a steadier readout of [`Time<Real>`](crate::Real) use
+    /// [`smoothed_fps`](Time::smoothed_fps).
+    #[inline]
+    pub fn fps(&self) -> f32 {
+        let delta = self.delta.as_secs_f32();
+        if delta > 0.0 {
+            1.0 / delta
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns how much time has advanced since [`startup`](#method.startup), as [`Duration`].
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
     /// Returns how much time has advanced since [`startup`](#method.startup), as [`f32`] seconds.
     ///
     /// **Note:** This is a monotonically increasing value. It's precision will degrade over time.
     /// If you need an `f32` but that precision loss is unacceptable,
     /// use [`elapsed_seconds_wrapped`](#method.elapsed_seconds_wrapped).
     #[inline]
-    pub fn elapsed(&self) -> Duration {
-        self.elapsed
+    pub fn elapsed_seconds(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+
+    /// Returns how much time has advanced since [`startup`](#method.startup), as [`f64`] seconds.
+    ///
+    /// This is computed from the exact [`Duration`], so it does not drift like summing
+    /// [`delta_seconds`](#method.delta_seconds) would, and stays precise for far longer than
+    /// [`elapsed_seconds`](#method.elapsed_seconds).
+    #[inline]
+    pub fn elapsed_seconds_f64(&self) -> f64 {
+        self.elapsed.as_secs_f64()
     }
 
     /// Returns how much time has advanced since [`startup`](#method.startup) modulo
@@ -219,6 +266,23 @@ impl<T: Default> Time<T> {
         self.elapsed_wrapped
     }
 
+    /// Returns how much time has advanced since [`startup`](#method.startup) modulo
+    /// the [`wrap_period`](#method.wrap_period), as [`f32`] seconds.
+    ///
+    /// This method is intended for applications (e.g. shaders) that require an [`f32`] value but
+    /// suffer from the gradual precision loss of [`elapsed_seconds`](#method.elapsed_seconds).
+    #[inline]
+    pub fn elapsed_seconds_wrapped(&self) -> f32 {
+        self.elapsed_wrapped.as_secs_f32()
+    }
+
+    /// Returns how much time has advanced since [`startup`](#method.startup) modulo
+    /// the [`wrap_period`](#method.wrap_period), as [`f64`] seconds.
+    #[inline]
+    pub fn elapsed_wrapped_f64(&self) -> f64 {
+        self.elapsed_wrapped.as_secs_f64()
+    }
+
     /// Returns a reference to the context of this specific clock.
     #[inline]
     pub fn context(&self) -> &T {
@@ -296,6 +360,18 @@ mod test {
         assert_eq!(time.elapsed(), Duration::from_millis(750));
     }
 
+    #[test]
+    fn test_fps() {
+        let mut time: Time = Time::default();
+        assert_eq!(time.fps(), 0.0);
+
+        time.advance_by(Duration::from_millis(250));
+        assert_eq!(time.fps(), 4.0);
+
+        time.advance_by(Duration::ZERO);
+        assert_eq!(time.fps(), 0.0);
+    }
+
     #[test]
     fn test_advance_to() {
         let mut time: Time = Time::default();
@@ -368,4 +444,30 @@ mod test {
         // that we notice if we change that behaviour.
         assert_eq!(time.elapsed_wrapped(), Duration::from_secs(0));
     }
+
+    #[test]
+    fn test_f64_accessors_keep_precision() {
+        let mut time: Time = Time::default();
+        time.set_wrap_period(Duration::from_secs(60));
+        let delta = Duration::from_nanos(16_666_667);
+        let steps = 100_000;
+
+        for _ in 0..steps {
+            time.advance_by(delta);
+        }
+
+        let true_sum = delta.as_nanos() as f64 * steps as f64 / 1e9;
+        let f64_error = (time.elapsed_seconds_f64() - true_sum).abs();
+        let f32_error = (time.elapsed_seconds() as f64 - true_sum).abs();
+        assert!(f64_error < f32_error);
+        assert!(f64_error < 1e-9);
+
+        assert_eq!(time.delta_seconds_f64(), delta.as_secs_f64());
+        assert_eq!(time.delta_seconds(), delta.as_secs_f32());
+        assert_eq!(
+            time.elapsed_wrapped_f64(),
+            time.elapsed_wrapped().as_secs_f64()
+        );
+        assert!(time.elapsed_wrapped_f64() < 60.0);
+    }
 }
@@ -1,4 +1,6 @@
 use crate::Stopwatch;
+#[cfg(feature = "multi-threaded")]
+use ens_tasks::{ParallelSliceMut, TaskPool};
 use std::time::Duration;
 
 /// Tracks elapsed time. Enters the finished state once `duration` is reached.
@@ -45,6 +47,20 @@ impl Timer {
         }
     }
 
+    /// Creates a new non-repeating timer with a given duration in seconds.
+    ///
+    /// Shorthand for `Timer::from_seconds(duration, TimerMode::Once)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use ens_time::*;
+    /// let timer = Timer::from_seconds_once(1.0);
+    /// assert_eq!(timer.mode(), TimerMode::Once);
+    /// ```
+    pub fn from_seconds_once(duration: f32) -> Self {
+        Self::from_seconds(duration, TimerMode::Once)
+    }
+
     /// Returns `true` if the timer has reached its duration.
     ///
     /// For repeating timers, this method behaves identically to [`Timer::just_finished`].
@@ -75,6 +91,10 @@ impl Timer {
 
     /// Returns `true` only on the tick the timer reached its duration.
     ///
+    /// A finished non-repeating timer stays [`finished`](Timer::finished) on later ticks, but is
+    /// no longer just finished until it is [`reset`](Timer::reset) and reaches its duration again.
+    /// A paused timer is never just finished.
+    ///
     /// # Examples
     /// ```
     /// # use ens_time::*;
@@ -366,7 +386,9 @@ impl Timer {
         1.0 - self.fraction()
     }
 
-    /// Returns the remaining time in seconds
+    /// Returns the remaining time in seconds.
+    ///
+    /// See [`Timer::remaining`].
     ///
     /// # Examples
     /// ```
@@ -383,7 +405,11 @@ impl Timer {
         self.remaining().as_secs_f32()
     }
 
-    /// Returns the remaining time using Duration
+    /// Returns the time left until the timer finishes.
+    ///
+    /// This is zero for a finished non-repeating timer. For a repeating timer it is the time left
+    /// in the current cycle. The result saturates at zero if the elapsed time was set past the
+    /// duration with [`Timer::set_elapsed`] or [`Timer::set_duration`].
     ///
     /// # Examples
     /// ```
@@ -395,7 +421,7 @@ impl Timer {
     /// ```
     #[inline(always)]
     pub fn remaining(&self) -> Duration {
-        self.duration() - self.elapsed()
+        self.duration().saturating_sub(self.elapsed())
     }
 
     /// Returns the number of times a repeating timer
@@ -422,6 +448,29 @@ impl Timer {
     }
 }
 
+/// Ticks every timer in `timers` by `delta` and returns how many of them
+/// [`just_finished`](Timer::just_finished).
+///
+/// See [`tick_all_par`] to tick large slices of timers on a [`TaskPool`](ens_tasks::TaskPool).
+pub fn tick_all(timers: &mut [Timer], delta: Duration) -> usize {
+    timers
+        .iter_mut()
+        .map(|timer| timer.tick(delta))
+        .filter(|timer| timer.just_finished())
+        .count()
+}
+
+/// Like [`tick_all`], but splits `timers` into chunks that are ticked in parallel on `pool`.
+///
+/// Spawning the tasks has a cost of its own, so this only pays off for many thousands of timers.
+#[cfg(feature = "multi-threaded")]
+pub fn tick_all_par(mut timers: &mut [Timer], delta: Duration, pool: &TaskPool) -> usize {
+    timers
+        .par_splat_map_mut(pool, None, |_, chunk| tick_all(chunk, delta))
+        .into_iter()
+        .sum()
+}
+
 /// Specifies [`Timer`] behavior.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 #[cfg_attr(feature = "serialize", derive(serde::Deserialize, serde::Serialize))]
@@ -601,4 +650,76 @@ mod tests {
         assert!(!t.just_finished());
         assert!(!t.finished());
     }
+
+    #[test]
+    fn just_finished_only_on_transition() {
+        let mut t = Timer::from_seconds_once(1.0);
+        t.tick(Duration::from_secs_f32(1.5));
+        assert!(t.just_finished());
+        assert!(t.finished());
+
+        t.tick(Duration::from_secs_f32(1.5));
+        assert!(!t.just_finished());
+        assert!(t.finished());
+
+        t.reset();
+        t.tick(Duration::from_secs_f32(1.0));
+        assert!(t.just_finished());
+        assert!(t.finished());
+    }
+
+    #[test]
+    fn remaining_saturates_when_finished() {
+        let mut t = Timer::from_seconds(1.0, TimerMode::Once);
+        t.tick(Duration::from_secs_f32(1.5));
+        assert_eq!(t.remaining(), Duration::ZERO);
+        assert_eq!(t.remaining_secs(), 0.0);
+
+        t.set_duration(Duration::from_secs_f32(0.5));
+        assert_eq!(t.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn remaining_repeating_mid_cycle() {
+        let mut t = Timer::from_seconds(2.0, TimerMode::Repeating);
+        t.tick(Duration::from_secs_f32(4.5));
+        assert_eq!(t.remaining_secs(), 1.5);
+        t.tick(Duration::from_secs_f32(1.0));
+        assert_eq!(t.remaining_secs(), 0.5);
+    }
+
+    fn staggered_timers() -> Vec<Timer> {
+        (0..1000)
+            .map(|i| Timer::new(Duration::from_millis(i % 100 + 1), TimerMode::Once))
+            .collect()
+    }
+
+    #[test]
+    fn tick_all_counts_just_finished() {
+        let mut timers = staggered_timers();
+
+        // Durations of 1..=50ms finish, ten timers each
+        assert_eq!(tick_all(&mut timers, Duration::from_millis(50)), 500);
+        assert_eq!(tick_all(&mut timers, Duration::from_millis(25)), 250);
+        // Finished timers don't count again
+        assert_eq!(tick_all(&mut timers, Duration::from_millis(100)), 250);
+        assert!(timers.iter().all(Timer::finished));
+    }
+
+    #[cfg(feature = "multi-threaded")]
+    #[test]
+    fn tick_all_par_matches_tick_all() {
+        let pool = TaskPool::new();
+        let mut timers = staggered_timers();
+        let mut expected = staggered_timers();
+
+        for millis in [50, 25, 100] {
+            let delta = Duration::from_millis(millis);
+            assert_eq!(
+                tick_all_par(&mut timers, delta, &pool),
+                tick_all(&mut expected, delta)
+            );
+        }
+        assert_eq!(timers, expected);
+    }
 }
@@ -0,0 +1,170 @@
+use crate::{real::Real, time::Time};
+
+/// The virtual game clock representing game time.
+///
+/// A specialization of the [`Time`] structure. **For method documentation, see
+/// [`Time<Virtual>#impl-Time<Virtual>`].**
+///
+/// Normally used as `Time<Virtual>`. It is automatically inserted as a resource
+/// by [`TimePlugin`](crate::TimePlugin) and updated based on
+/// [`Time<Real>`](crate::Real). The virtual clock is automatically set as the
+/// default generic [`Time`] resource for the update.
+///
+/// The virtual clock differs from real time clock in that it can be paused and
+/// its speed can be scaled, which makes it suitable for gameplay, slow-motion
+/// effects and pause menus. Systems that should keep running at wall clock speed, such as UI,
+/// should read [`Time<Real>`](crate::Real) instead.
+///
+/// The speed is set with [`set_relative_speed()`](Time::set_relative_speed), which multiplies
+/// every real delta before it is added to the virtual clock. While the clock is
+/// [paused](Time::pause), it advances by [`Duration::ZERO`](std::time::Duration::ZERO) every
+/// update.
+///
+/// A relative speed of `0.0` is treated as a pause: [`is_paused()`](Time::is_paused) returns
+/// `true` and the previous speed is kept, so [`unpause()`](Time::unpause) resumes at that speed
+/// rather than staying frozen.
+///
+/// The [`FixedUpdate`](ens_app::FixedUpdate) schedule follows the virtual clock, so pausing
+/// or slowing it down also pauses or slows down fixed timestep logic.
+#[derive(Debug, Copy, Clone)]
+pub struct Virtual {
+    paused: bool,
+    relative_speed: f64,
+}
+
+impl Time<Virtual> {
+    /// Returns the speed the clock advances relative to your system clock, as [`f32`].
+    ///
+    /// This is the speed the clock runs at when it is not paused, so it is unaffected by
+    /// [`pause()`](Time::pause). Setting a speed of `0.0` pauses the clock instead of changing this
+    /// value.
+    #[inline]
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed_f64() as f32
+    }
+
+    /// Returns the speed the clock advances relative to your system clock, as [`f64`].
+    ///
+    /// See [`relative_speed()`](Time::relative_speed).
+    #[inline]
+    pub fn relative_speed_f64(&self) -> f64 {
+        self.context().relative_speed
+    }
+
+    /// Returns the speed the clock currently advances at, which is `0.0` while paused and
+    /// [`relative_speed()`](Time::relative_speed) otherwise.
+    #[inline]
+    pub fn effective_speed(&self) -> f32 {
+        self.effective_speed_f64() as f32
+    }
+
+    /// Returns the speed the clock currently advances at, as [`f64`].
+    ///
+    /// See [`effective_speed()`](Time::effective_speed).
+    #[inline]
+    pub fn effective_speed_f64(&self) -> f64 {
+        if self.context().paused {
+            0.0
+        } else {
+            self.context().relative_speed
+        }
+    }
+
+    /// Sets the speed the clock advances relative to your system clock, given as an [`f32`].
+    ///
+    /// For example, setting this to `2.0` will make the clock advance twice as fast as your system
+    /// clock. Setting it to `0.0` pauses the clock like [`pause()`](Time::pause) and keeps the
+    /// previous speed for [`unpause()`](Time::unpause). Any other speed also unpauses the clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed(&mut self, ratio: f32) {
+        self.set_relative_speed_f64(ratio as f64);
+    }
+
+    /// Sets the speed the clock advances relative to your system clock, given as an [`f64`].
+    ///
+    /// See [`set_relative_speed()`](Time::set_relative_speed).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed_f64(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        let context = self.context_mut();
+        if ratio == 0.0 {
+            context.paused = true;
+        } else {
+            context.paused = false;
+            context.relative_speed = ratio;
+        }
+    }
+
+    /// Stops the clock, preventing it from advancing until resumed.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.context_mut().paused = true;
+    }
+
+    /// Resumes the clock at its [`relative_speed()`](Time::relative_speed).
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.context_mut().paused = false;
+    }
+
+    /// Returns `true` if the clock is currently paused, either by [`pause()`](Time::pause) or by
+    /// a relative speed of `0.0`.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.context().paused
+    }
+
+    /// Advances the virtual clock by the delta of `real` scaled by the
+    /// [`effective_speed()`](Time::effective_speed).
+    ///
+    /// This is called by the time system added by [`TimePlugin`](crate::TimePlugin).
+    pub fn advance_with_real(&mut self, real: &Time<Real>) {
+        let speed = self.effective_speed_f64();
+        let delta = if speed == 1.0 {
+            real.delta()
+        } else {
+            real.delta().mul_f64(speed)
+        };
+        self.advance_by(delta);
+    }
+}
+
+impl Default for Virtual {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            relative_speed: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_zero_speed_pauses_and_keeps_previous_speed() {
+        let mut time = Time::<Virtual>::default();
+
+        time.set_relative_speed(1.5);
+        time.set_relative_speed(0.0);
+
+        assert!(time.is_paused());
+        assert_eq!(time.relative_speed(), 1.5);
+        assert_eq!(time.effective_speed(), 0.0);
+
+        time.unpause();
+
+        assert!(!time.is_paused());
+        assert_eq!(time.effective_speed(), 1.5);
+    }
+}
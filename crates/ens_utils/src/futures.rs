@@ -5,10 +5,13 @@ use std::{
     task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
 };
 
+pub use std::future::{ready, Ready};
+
 /// Consumes a future, polls it once, and immediately returns the output
 /// or returns `None` if it wasn't ready yet.
 ///
-/// This will cancel the future if it's not ready.
+/// This will cancel the future if it's not ready. The future is polled with a waker that does
+/// nothing, so it is never woken up afterwards.
 pub fn now_or_never<F: Future>(mut future: F) -> Option<F::Output> {
     let noop_waker = noop_waker();
     let mut cx = Context::from_waker(&noop_waker);
@@ -52,3 +55,19 @@ fn noop_waker() -> Waker {
     // (which talks about retaining and releasing any "resources", of which there are none in this case)
     unsafe { Waker::from_raw(noop_raw_waker()) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{now_or_never, ready};
+
+    #[test]
+    fn now_or_never_ready() {
+        assert_eq!(now_or_never(ready(7)), Some(7));
+        assert_eq!(now_or_never(async { 7 }), Some(7));
+    }
+
+    #[test]
+    fn now_or_never_pending() {
+        assert_eq!(now_or_never(std::future::pending::<()>()), None);
+    }
+}
@@ -82,6 +82,53 @@ pub type HashSet<K> = hashbrown::HashSet<K, BuildHasherDefault<AHasher>>;
 )]
 pub type StableHashSet<K> = hashbrown::HashSet<K, FixedState>;
 
+/// Constructors for [`HashMap`]s and [`HashSet`]s hashed with [`FixedState`].
+///
+/// Maps built this way iterate in the same order for the same insertions and deletions, even
+/// across executions of the program, which makes them useful for reproducible tests.
+///
+/// [`FixedState`] uses hard-coded seeds, so it must never be used where resistance to hash flooding
+/// matters, e.g. for keys coming from the network or other untrusted input.
+pub trait FixedHashExt {
+    /// The collection type built with [`FixedState`].
+    type Fixed;
+
+    /// Creates an empty collection hashed with [`FixedState`].
+    fn fixed() -> Self::Fixed;
+
+    /// Creates an empty collection hashed with [`FixedState`], with space for at least
+    /// `capacity` elements.
+    fn fixed_with_capacity(capacity: usize) -> Self::Fixed;
+}
+
+impl<K, V> FixedHashExt for HashMap<K, V> {
+    type Fixed = hashbrown::HashMap<K, V, FixedState>;
+
+    #[inline]
+    fn fixed() -> Self::Fixed {
+        hashbrown::HashMap::with_hasher(FixedState)
+    }
+
+    #[inline]
+    fn fixed_with_capacity(capacity: usize) -> Self::Fixed {
+        hashbrown::HashMap::with_capacity_and_hasher(capacity, FixedState)
+    }
+}
+
+impl<K> FixedHashExt for HashSet<K> {
+    type Fixed = hashbrown::HashSet<K, FixedState>;
+
+    #[inline]
+    fn fixed() -> Self::Fixed {
+        hashbrown::HashSet::with_hasher(FixedState)
+    }
+
+    #[inline]
+    fn fixed_with_capacity(capacity: usize) -> Self::Fixed {
+        hashbrown::HashSet::with_capacity_and_hasher(capacity, FixedState)
+    }
+}
+
 /// A pre-hashed value of a specific type. Pre-hashing enables memoization of hashes that are expensive to compute.
 /// It also enables faster [`PartialEq`] comparisons by short circuiting on hash equality.
 /// See [`PassHash`] and [`PassHasher`] for a "pass through" [`BuildHasher`] and [`Hasher`] implementation
@@ -432,4 +479,27 @@ mod tests {
             map_2.iter().collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn fixed_iteration_order() {
+        let mut map_1 = HashMap::fixed();
+        let mut map_2 = HashMap::fixed();
+        let mut set_1 = HashSet::fixed();
+        let mut set_2 = HashSet::fixed();
+        for i in (1..40).rev() {
+            let key = format!("key {i}");
+            map_1.insert(key.clone(), i);
+            map_2.insert(key.clone(), i);
+            set_1.insert(key.clone());
+            set_2.insert(key);
+        }
+        assert_eq!(
+            map_1.iter().collect::<Vec<_>>(),
+            map_2.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            set_1.iter().collect::<Vec<_>>(),
+            set_2.iter().collect::<Vec<_>>()
+        );
+    }
 }
@@ -141,6 +141,19 @@ impl<T: Internable + ?Sized> Interner<T> {
     /// [`Interned<T>`] using the obtained static reference. Subsequent calls for the same `value`
     /// will return [`Interned<T>`] using the same static reference.
     pub fn intern(&self, value: &T) -> Interned<T> {
+        self.intern_with(value, || value.leak())
+    }
+
+    /// Return the [`Interned<T>`] corresponding to the static `value`.
+    ///
+    /// Unlike [`Interner::intern`], this never leaks a copy of `value`: if no equal value was
+    /// interned before, `value` itself is stored. Otherwise the previously interned reference is
+    /// returned, which may point to a different but equal value.
+    pub fn intern_static(&self, value: &'static T) -> Interned<T> {
+        self.intern_with(value, || value)
+    }
+
+    fn intern_with(&self, value: &T, leak: impl FnOnce() -> &'static T) -> Interned<T> {
         let lock = self.0.get_or_init(Default::default);
         {
             let set = lock.read().unwrap_or_else(PoisonError::into_inner);
@@ -153,12 +166,19 @@ impl<T: Internable + ?Sized> Interner<T> {
             if let Some(value) = set.get(value) {
                 Interned(*value)
             } else {
-                let leaked = value.leak();
+                let leaked = leak();
                 set.insert(leaked);
                 Interned(leaked)
             }
         }
     }
+
+    /// Returns the [`Interned<T>`] corresponding to `value` if it was already interned, without
+    /// interning it otherwise.
+    pub fn get(&self, value: &T) -> Option<Interned<T>> {
+        let set = self.0.get()?.read().unwrap_or_else(PoisonError::into_inner);
+        set.get(value).map(|value| Interned(*value))
+    }
 }
 
 impl<T: ?Sized> Default for Interner<T> {
@@ -268,6 +288,26 @@ mod tests {
         assert_eq!(hash_a, hash_b);
     }
 
+    #[test]
+    fn intern_static_reuses_reference() {
+        static STATIC: &str = "static label";
+        let interner = Interner::new();
+
+        let a = interner.intern_static(STATIC);
+        let b = interner.intern_static(STATIC);
+        assert!(std::ptr::eq(a.0, STATIC));
+        assert!(std::ptr::eq(a.0, b.0));
+
+        // Owned strings with the same content resolve to the static reference.
+        let owned = interner.intern(&String::from("static label"));
+        assert!(std::ptr::eq(owned.0, STATIC));
+
+        // Owned strings with new content are still interned.
+        let other = interner.intern(&String::from("other label"));
+        assert_eq!(&*other, "other label");
+        assert!(std::ptr::eq(other.0, interner.intern("other label").0));
+    }
+
     #[test]
     fn same_interned_content() {
         let a = Interned::<str>(Box::leak(Box::new("A".to_string())));
@@ -87,6 +87,17 @@ impl<F: FnOnce()> OnDrop<F> {
             callback: ManuallyDrop::new(callback),
         }
     }
+
+    /// Consumes this guard without invoking the callback.
+    ///
+    /// Unlike passing the guard to [`std::mem::forget`], this drops the callback, and with it
+    /// anything it captured.
+    pub fn disarm(self) {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the callback can't be taken again by `Drop`.
+        let callback = unsafe { ManuallyDrop::take(&mut this.callback) };
+        drop(callback);
+    }
 }
 
 impl<F: FnOnce()> Drop for OnDrop<F> {
@@ -96,3 +107,47 @@ impl<F: FnOnce()> Drop for OnDrop<F> {
         callback();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OnDrop;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn disarm_drops_callback_without_calling_it() {
+        struct Captured<'a>(&'a Cell<u32>);
+
+        impl Drop for Captured<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let calls = Rc::new(Cell::new(0));
+        let drops = Cell::new(0);
+
+        let captured = Captured(&drops);
+        let guard = OnDrop::new({
+            let calls = calls.clone();
+            move || {
+                let _captured = &captured;
+                calls.set(calls.get() + 1);
+            }
+        });
+        guard.disarm();
+        assert_eq!(calls.get(), 0);
+        assert_eq!(drops.get(), 1);
+
+        let captured = Captured(&drops);
+        let guard = OnDrop::new({
+            let calls = calls.clone();
+            move || {
+                let _captured = &captured;
+                calls.set(calls.get() + 1);
+            }
+        });
+        drop(guard);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(drops.get(), 2);
+    }
+}
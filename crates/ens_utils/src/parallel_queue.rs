@@ -1,4 +1,7 @@
-use core::cell::Cell;
+use core::{
+    cell::Cell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use thread_local::ThreadLocal;
 
 /// A cohesive set of thread-local values of a given type.
@@ -70,3 +73,121 @@ impl<T: Send> Parallel<Vec<T>> {
         }
     }
 }
+
+/// A set of thread-local queues holding at most a fixed number of items in total.
+///
+/// Like [`Parallel<Vec<T>>`], each thread pushes to its own queue, so pushing only synchronizes
+/// on a single atomic counter. Once the capacity is reached, [`BoundedParallelQueue::push`]
+/// rejects new items until some are removed with [`BoundedParallelQueue::try_drain`], which lets
+/// producers apply backpressure instead of growing memory without bound.
+pub struct BoundedParallelQueue<T: Send> {
+    locals: ThreadLocal<Cell<Vec<T>>>,
+    len: AtomicUsize,
+    capacity: usize,
+}
+
+impl<T: Send> BoundedParallelQueue<T> {
+    /// Creates an empty queue holding at most `capacity` items.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            locals: ThreadLocal::new(),
+            len: AtomicUsize::new(0),
+            capacity,
+        }
+    }
+
+    /// The maximum number of items this queue can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of items currently enqueued across all threads.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if no items are enqueued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Enqueues `item` on the current thread's queue.
+    ///
+    /// Returns the item back as an error if the queue is full.
+    pub fn push(&self, item: T) -> Result<(), T> {
+        let reserved = self
+            .len
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |len| {
+                (len < self.capacity).then_some(len + 1)
+            });
+        if reserved.is_err() {
+            return Err(item);
+        }
+
+        let cell = self.locals.get_or_default();
+        let mut queue = cell.take();
+        queue.push(item);
+        cell.set(queue);
+        Ok(())
+    }
+
+    /// Moves up to `max` enqueued items from all threads to the end of `out`, freeing space for
+    /// new items, and returns how many were moved.
+    ///
+    /// Items pushed by the same thread are drained in the order they were pushed, but the
+    /// ordering between threads is not guaranteed.
+    pub fn try_drain(&mut self, max: usize, out: &mut Vec<T>) -> usize {
+        let mut remaining = max.min(*self.len.get_mut());
+        let drained = remaining;
+        out.reserve(remaining);
+        for queue in self.locals.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            let queue = queue.get_mut();
+            let count = remaining.min(queue.len());
+            out.extend(queue.drain(..count));
+            remaining -= count;
+        }
+        *self.len.get_mut() -= drained;
+        drained
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedParallelQueue;
+
+    #[test]
+    fn bounded_queue_rejects_when_full() {
+        let mut queue = BoundedParallelQueue::new(4);
+        std::thread::scope(|scope| {
+            for thread in 0..2 {
+                let queue = &queue;
+                scope.spawn(move || {
+                    for i in 0..2 {
+                        queue.push(thread * 10 + i).unwrap();
+                    }
+                });
+            }
+        });
+        assert_eq!(queue.len(), 4);
+        assert_eq!(queue.push(100), Err(100));
+
+        let mut out = Vec::new();
+        assert_eq!(queue.try_drain(3, &mut out), 3);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.push(101), Ok(()));
+        assert_eq!(queue.push(102), Ok(()));
+        assert_eq!(queue.push(103), Ok(()));
+        assert_eq!(queue.push(104), Err(104));
+
+        assert_eq!(queue.try_drain(usize::MAX, &mut out), 4);
+        assert!(queue.is_empty());
+        out.sort_unstable();
+        assert_eq!(out, [0, 1, 10, 11, 101, 102, 103]);
+    }
+}
@@ -4,6 +4,10 @@
 /// [`std::any::type_name`], but with the prefix of all paths removed. For
 /// example, the short name of `alloc::vec::Vec<core::option::Option<u32>>`
 /// would be `Vec<Option<u32>>`.
+///
+/// Lifetimes carry no information in a short name and are removed, so
+/// `ens::system::Query<'w, 's, (&'static Foo, &'static mut bar::Bar)>` becomes
+/// `Query<(&Foo, &mut Bar)>`.
 pub fn get_short_name(full_name: &str) -> String {
     // Generics result in nested paths within <..> blocks.
     // To tackle this, we parse the string from left to right, collapsing as we go.
@@ -30,10 +34,46 @@ pub fn get_short_name(full_name: &str) -> String {
             let segment_to_collapse = rest_of_string
                 .get(0..special_character_index)
                 .unwrap_or_default();
-            parsed_name += collapse_type_name(segment_to_collapse);
-            // Insert the special character
             let special_character =
                 &rest_of_string[special_character_index..=special_character_index];
+
+            if segment_to_collapse.starts_with('\'') {
+                // Drop lifetime parameters along with their separator
+                match special_character {
+                    "," if rest_of_string[special_character_index + 1..].starts_with(' ') => {
+                        index += special_character_index + 2;
+                        continue;
+                    }
+                    "," => {
+                        index += special_character_index + 1;
+                        continue;
+                    }
+                    ">" if parsed_name.ends_with('<') => {
+                        // Every generic parameter was a lifetime
+                        parsed_name.pop();
+                        index += special_character_index + 1;
+                        if full_name[index..].starts_with("::") {
+                            parsed_name.push_str("::");
+                            index += 2;
+                        }
+                        continue;
+                    }
+                    _ => trim_lifetime_bound(&mut parsed_name),
+                }
+            } else if special_character == " "
+                && segment_to_collapse
+                    .trim_start_matches('&')
+                    .starts_with('\'')
+            {
+                // Drop the lifetime of a reference
+                parsed_name += segment_to_collapse.split('\'').next().unwrap_or_default();
+                index += special_character_index + 1;
+                continue;
+            } else {
+                push_collapsed_type_name(&mut parsed_name, segment_to_collapse);
+            }
+
+            // Insert the special character
             parsed_name.push_str(special_character);
 
             match special_character {
@@ -49,13 +89,31 @@ pub fn get_short_name(full_name: &str) -> String {
             }
         } else {
             // If there are no special characters left, we're done!
-            parsed_name += collapse_type_name(rest_of_string);
+            if rest_of_string.starts_with('\'') {
+                trim_lifetime_bound(&mut parsed_name);
+            } else {
+                push_collapsed_type_name(&mut parsed_name, rest_of_string);
+            }
             index = end_of_string;
         }
     }
     parsed_name
 }
 
+/// Pushes the collapsed `segment`, keeping the `&` of references in front of it.
+fn push_collapsed_type_name(parsed_name: &mut String, segment: &str) {
+    let type_name = segment.trim_start_matches('&');
+    parsed_name.push_str(&segment[..segment.len() - type_name.len()]);
+    parsed_name.push_str(collapse_type_name(type_name));
+}
+
+/// Removes the ` + ` preceding a dropped lifetime bound, as in `dyn Trait + 'static`.
+fn trim_lifetime_bound(parsed_name: &mut String) {
+    if parsed_name.ends_with(" + ") {
+        parsed_name.truncate(parsed_name.len() - 3);
+    }
+}
+
 #[inline(always)]
 fn collapse_type_name(string: &str) -> &str {
     // Enums types are retained.
@@ -158,4 +216,42 @@ mod name_formatting_tests {
             "[i32; 16]::default".to_string()
         );
     }
+
+    #[test]
+    fn lifetimes_and_nested_paths() {
+        let cases = [
+            // Tuples
+            ("(a::A, (b::B, c::C))", "(A, (B, C))"),
+            ("(a::A,)", "(A,)"),
+            // Nested generics
+            (
+                "alloc::vec::Vec<core::option::Option<alloc::boxed::Box<a::A>>>",
+                "Vec<Option<Box<A>>>",
+            ),
+            ("a::Map<a::Key, b::Vec<c::Value>>", "Map<Key, Vec<Value>>"),
+            // Arrays and slices
+            ("[a::A; 4]", "[A; 4]"),
+            ("&[a::Vec<b::B>]", "&[Vec<B>]"),
+            // References
+            ("&'static a::A", "&A"),
+            ("&'a mut a::A", "&mut A"),
+            ("&a::A", "&A"),
+            ("&&'a a::A", "&&A"),
+            // Lifetime parameters and bounds
+            ("a::Ref<'a>", "Ref"),
+            ("a::Ref<'a>::get", "Ref::get"),
+            ("a::Ref<'a, 'b, b::B>", "Ref<B>"),
+            (
+                "alloc::boxed::Box<dyn a::Trait + 'static>",
+                "Box<dyn Trait>",
+            ),
+            (
+                "ens::system::Query<'w, 's, (&'static Foo, &'static mut bar::Bar)>",
+                "Query<(&Foo, &mut Bar)>",
+            ),
+        ];
+        for (full_name, short_name) in cases {
+            assert_eq!(get_short_name(full_name), short_name, "{full_name}");
+        }
+    }
 }
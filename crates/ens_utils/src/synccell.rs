@@ -31,7 +31,19 @@ impl<T: ?Sized> SyncCell<T> {
         &mut self.inner
     }
 
+    /// Get a mutable reference to this `SyncCell`'s inner value.
+    ///
+    /// This is safe for any `T`: `&mut self` guarantees that no other thread can access the
+    /// inner value at the same time, so no synchronization is needed.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
     /// For types that implement [`Sync`], get shared access to this `SyncCell`'s inner value.
+    ///
+    /// Note that for a `SyncCell<Box<T>>`, this requires `Box<T>` and therefore `T` to be
+    /// [`Sync`]. Wrapping a value in a [`Box`] doesn't make it shareable.
     pub fn read(&self) -> &T
     where
         T: Sync,
@@ -51,3 +63,18 @@ impl<T: ?Sized> SyncCell<T> {
 // As `SyncCell` requires an exclusive reference to access the wrapped value for `!Sync` types,
 // marking this type as `Sync` does not actually allow unsynchronized access to the inner value.
 unsafe impl<T: ?Sized> Sync for SyncCell<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::SyncCell;
+    use std::cell::Cell;
+
+    #[test]
+    fn get_mut() {
+        // `Cell` is not `Sync`, but `&mut` access is still allowed.
+        let mut cell = SyncCell::new(Cell::new(1));
+        cell.get_mut().set(2);
+        *cell.get_mut() = Cell::new(cell.get_mut().get() * 2);
+        assert_eq!(SyncCell::to_inner(cell).get(), 4);
+    }
+}